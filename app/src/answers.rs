@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/*
+A local record of accepted answers, so a change to a solution that alters a previously accepted
+answer is noticed. Answers are keyed by day, part, and a hash of the input they were computed
+from, so the same store can hold answers for the test inputs and the real inputs.
+
+The file has one tab-separated entry per line: day, part, input hash (hex), answer. Answers may
+span multiple lines (e.g. day 13's rendered letters), so backslashes, tabs, and newlines in the
+answer are escaped.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AnswerKey {
+    pub day: u8,
+    pub part: char,
+    pub input_hash: u64,
+}
+
+impl AnswerKey {
+    pub fn new(day: u8, part: char, input: &[u8]) -> Self {
+        AnswerKey {
+            day,
+            part,
+            input_hash: hash_input(input),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseAnswerError {
+    WrongNumFields(usize),
+    BadDay(String),
+    BadPart(String),
+    BadHash(String),
+    BadEscape(String),
+}
+
+impl fmt::Display for ParseAnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongNumFields(n) => write!(f, "expected 4 fields, found {}", n),
+            Self::BadDay(s) => write!(f, "invalid day {:?}", s),
+            Self::BadPart(s) => write!(f, "invalid part {:?}", s),
+            Self::BadHash(s) => write!(f, "invalid input hash {:?}", s),
+            Self::BadEscape(s) => write!(f, "invalid escape in answer {:?}", s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    // No answer has been accepted for this key
+    New,
+    Matches,
+    Changed { accepted: String },
+}
+
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+impl AnswerStore {
+    // A missing file is treated as an empty store.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+        let mut answers = BTreeMap::new();
+        for (num, line) in contents.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let (key, answer) = parse_entry(line).map_err(|err| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{}:{}: {}", path.display(), num + 1, err),
+                )
+            })?;
+            answers.insert(key, answer);
+        }
+        Ok(AnswerStore { path, answers })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut contents = String::new();
+        for (key, answer) in self.answers.iter() {
+            contents += &format!(
+                "{:02}\t{}\t{:016x}\t{}\n",
+                key.day,
+                key.part,
+                key.input_hash,
+                escape(answer)
+            );
+        }
+        fs::write(&self.path, contents)
+    }
+
+    pub fn get(&self, key: &AnswerKey) -> Option<&str> {
+        self.answers.get(key).map(|s| s.as_str())
+    }
+
+    pub fn check(&self, key: &AnswerKey, answer: &str) -> Check {
+        match self.get(key) {
+            None => Check::New,
+            Some(accepted) if accepted == answer => Check::Matches,
+            Some(accepted) => Check::Changed {
+                accepted: accepted.to_string(),
+            },
+        }
+    }
+
+    // Returns the previously accepted answer, if any
+    pub fn accept(&mut self, key: AnswerKey, answer: &str) -> Option<String> {
+        self.answers.insert(key, answer.to_string())
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+// 64-bit FNV-1a, which is stable across platforms and Rust versions, unlike DefaultHasher.
+pub fn hash_input(input: &[u8]) -> u64 {
+    input.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn parse_entry(line: &str) -> Result<(AnswerKey, String), ParseAnswerError> {
+    let fields: Vec<&str> = line.splitn(4, '\t').collect();
+    if fields.len() != 4 {
+        return Err(ParseAnswerError::WrongNumFields(fields.len()));
+    }
+    let day = fields[0]
+        .parse::<u8>()
+        .map_err(|_| ParseAnswerError::BadDay(fields[0].to_string()))?;
+    let part = match fields[1].parse::<char>() {
+        Ok(c @ ('a' | 'b')) => c,
+        _ => return Err(ParseAnswerError::BadPart(fields[1].to_string())),
+    };
+    let input_hash = u64::from_str_radix(fields[2], 16)
+        .map_err(|_| ParseAnswerError::BadHash(fields[2].to_string()))?;
+    let answer = unescape(fields[3])?;
+    let key = AnswerKey {
+        day,
+        part,
+        input_hash,
+    };
+    Ok((key, answer))
+}
+
+fn escape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\t' => result.push_str("\\t"),
+            '\n' => result.push_str("\\n"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+fn unescape(s: &str) -> Result<String, ParseAnswerError> {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => result.push('\\'),
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            _ => return Err(ParseAnswerError::BadEscape(s.to_string())),
+        }
+    }
+    Ok(result)
+}
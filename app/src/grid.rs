@@ -42,7 +42,7 @@ impl<T> Grid<T> {
         Self::new_with_cells(cells, self.rows, self.cols)
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             grid: self,
             x: 0,
@@ -1,8 +1,10 @@
 use std::env;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader};
 
+pub mod answers;
 pub mod grid;
+pub mod parallel;
 pub mod point;
 
 pub fn input_arg() -> String {
@@ -20,7 +22,12 @@ pub fn read_lines(path: &str) -> impl Iterator<Item = String> {
     let reader = BufReader::new(f);
     reader
         .lines()
-        .into_iter()
+        .map(|x| x.expect("Unable to read input line"))
+}
+
+pub fn read_stdin_lines() -> impl Iterator<Item = String> {
+    io::stdin()
+        .lines()
         .map(|x| x.expect("Unable to read input line"))
 }
 
@@ -30,3 +37,7 @@ pub fn read_uints(path: &str) -> impl Iterator<Item = usize> {
             .expect("Line was not an unsigned integer")
     })
 }
+
+pub fn read_ints(path: &str) -> impl Iterator<Item = isize> {
+    read_lines(path).map(|x| x.parse::<isize>().expect("Line was not an integer"))
+}
@@ -0,0 +1,81 @@
+use std::cell::Cell;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+thread_local! {
+    // The most threads map may use when called on this thread, if limited
+    static BUDGET: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+pub fn num_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+// Runs f with map limited to the given number of threads, so work that's already spread across a
+// pool of threads doesn't start a full pool of its own
+pub fn with_threads<R>(threads: usize, f: impl FnOnce() -> R) -> R {
+    // Restores the previous limit even if f panics
+    struct Restore(Option<usize>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            BUDGET.set(self.0);
+        }
+    }
+    let _restore = Restore(BUDGET.replace(Some(threads.max(1))));
+    f()
+}
+
+// Maps f over items using scoped worker threads, returning results in the same order as items.
+// Workers claim one item at a time, so uneven item costs still balance across threads.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    map_with_threads(items, BUDGET.get().unwrap_or_else(num_threads), f)
+}
+
+// A map called from f runs on the calling worker alone
+pub fn map_with_threads<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let threads = threads.min(items.len());
+    if threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|s| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(|| {
+                    with_threads(1, || {
+                        let mut done = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            match items.get(index) {
+                                Some(item) => done.push((index, f(item))),
+                                None => break done,
+                            }
+                        }
+                    })
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            // Passes a worker's panic on as it was, so its message isn't lost
+            .flat_map(|w| {
+                w.join()
+                    .unwrap_or_else(|payload| panic::resume_unwind(payload))
+            })
+            .collect()
+    });
+    results.sort_unstable_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, r)| r).collect()
+}
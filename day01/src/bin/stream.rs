@@ -0,0 +1,45 @@
+use std::env;
+use std::num::NonZeroUsize;
+
+use day01::DepthMonitor;
+
+const DEFAULT_WINDOW: NonZeroUsize = NonZeroUsize::MIN;
+const DEFAULT_INTERVAL: NonZeroUsize = NonZeroUsize::new(1000).unwrap();
+
+// Reads depths from stdin, reporting the running increase count every INTERVAL depths.
+// Usage: stream [WINDOW] [INTERVAL]
+fn main() {
+    let mut args = env::args().skip(1).map(|arg| {
+        arg.parse::<NonZeroUsize>()
+            .expect("Arguments must be positive integers")
+    });
+    let window = args.next().unwrap_or(DEFAULT_WINDOW);
+    let interval = args.next().unwrap_or(DEFAULT_INTERVAL).get();
+
+    let mut monitor = DepthMonitor::new(window);
+    for (num, line) in app::read_stdin_lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse::<isize>() {
+            Ok(depth) => monitor.push(depth),
+            Err(_) => {
+                eprintln!("Skipping line {}: not an integer: {}", num + 1, line);
+                continue;
+            }
+        }
+        if monitor.count().is_multiple_of(interval) {
+            println!(
+                "After {} depths there are {} increases",
+                monitor.count(),
+                monitor.increases()
+            );
+        }
+    }
+    println!(
+        "After {} depths there are {} increases (final)",
+        monitor.count(),
+        monitor.increases()
+    );
+}
@@ -0,0 +1,148 @@
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+
+// The most recent values, up to a fixed number of them, and their sum
+struct Window {
+    values: VecDeque<isize>,
+    size: usize,
+    sum: isize,
+}
+
+impl Window {
+    fn new(size: NonZeroUsize) -> Self {
+        let size = size.get();
+        Window {
+            values: VecDeque::with_capacity(size),
+            size,
+            sum: 0,
+        }
+    }
+
+    // Adds a value, returning the sum once the window is full
+    fn push(&mut self, x: isize) -> Option<isize> {
+        self.values.push_back(x);
+        self.sum += x;
+        if self.values.len() > self.size {
+            self.sum -= self.values.pop_front().expect("Window is not empty");
+        }
+        (self.values.len() == self.size).then_some(self.sum)
+    }
+}
+
+/*
+Counts how often the sum of a sliding window increases from one position to the next. A window
+of 1 compares individual values. Only the window itself is buffered, so any length of input can
+be processed.
+*/
+pub fn sliding_increases(xs: impl IntoIterator<Item = isize>, window: NonZeroUsize) -> usize {
+    let mut monitor = DepthMonitor::new(window);
+    xs.into_iter().for_each(|x| monitor.push(x));
+    monitor.increases()
+}
+
+/*
+Tracks window sum increases one depth at a time, for input that arrives incrementally (e.g. a
+live stream on stdin). Memory use is bounded by the window size rather than the input length.
+*/
+pub struct DepthMonitor {
+    window: Window,
+    prev_sum: Option<isize>,
+    count: usize,
+    increases: usize,
+}
+
+impl DepthMonitor {
+    pub fn new(window: NonZeroUsize) -> Self {
+        DepthMonitor {
+            window: Window::new(window),
+            prev_sum: None,
+            count: 0,
+            increases: 0,
+        }
+    }
+
+    pub fn push(&mut self, x: isize) {
+        self.count += 1;
+        if let Some(sum) = self.window.push(x) {
+            if self.prev_sum.is_some_and(|prev| sum > prev) {
+                self.increases += 1;
+            }
+            self.prev_sum = Some(sum);
+        }
+    }
+
+    // The number of depths pushed so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn increases(&self) -> usize {
+        self.increases
+    }
+
+    // The sum of the most recent full window, if enough depths have been pushed
+    pub fn prev_sum(&self) -> Option<isize> {
+        self.prev_sum
+    }
+}
+
+// The length of the longest run of strictly increasing values.
+pub fn longest_increasing_run(xs: impl IntoIterator<Item = isize>) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut prev = None;
+    for x in xs {
+        current = match prev {
+            Some(p) if x > p => current + 1,
+            _ => 1,
+        };
+        longest = longest.max(current);
+        prev = Some(x);
+    }
+    longest
+}
+
+pub fn window_sums<I>(xs: I, window: NonZeroUsize) -> WindowSums<I::IntoIter>
+where
+    I: IntoIterator<Item = isize>,
+{
+    WindowSums {
+        xs: xs.into_iter(),
+        window: Window::new(window),
+    }
+}
+
+pub fn moving_average(
+    xs: impl IntoIterator<Item = isize>,
+    window: NonZeroUsize,
+) -> impl Iterator<Item = f64> {
+    window_sums(xs, window).map(move |sum| sum as f64 / window.get() as f64)
+}
+
+// Yields the sum of each full window, so n values produce n - window + 1 sums.
+pub struct WindowSums<I> {
+    xs: I,
+    window: Window,
+}
+
+impl<I: Iterator<Item = isize>> Iterator for WindowSums<I> {
+    type Item = isize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(sum) = self.window.push(self.xs.next()?) {
+                return Some(sum);
+            }
+        }
+    }
+}
+
+// Depth increases from one reading to the next
+pub fn part_a(path: &str) -> String {
+    sliding_increases(app::read_ints(path), NonZeroUsize::MIN).to_string()
+}
+
+// Increases between sums of three readings
+pub fn part_b(path: &str) -> String {
+    sliding_increases(app::read_ints(path), NonZeroUsize::new(3).unwrap()).to_string()
+}
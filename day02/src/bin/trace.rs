@@ -0,0 +1,66 @@
+use std::env;
+use std::process;
+
+use day02::{Mode, Program, Trajectory};
+
+const USAGE: &str = "Usage: trace (a|b) (csv|svg|summary) [FILE] [DEPTH]";
+
+// Prints the trajectory of a command file in either mode, for debugging programs visually
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let usage = || -> ! {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let mode = match args.first().map(|s| s.as_str()) {
+        Some("a") => Mode::Direct,
+        Some("b") => Mode::Aimed,
+        _ => usage(),
+    };
+    let format = args.get(1).map(|s| s.as_str()).unwrap_or_else(|| usage());
+    let path = args.get(2).map_or("input.txt", |s| s.as_str());
+
+    let program = Program::from_lines(app::read_lines(path)).unwrap_or_else(|err| {
+        eprintln!("Invalid program: {}", err);
+        process::exit(1);
+    });
+    let trajectory = Trajectory::record(mode, &program).unwrap_or_else(|err| {
+        eprintln!("Invalid program: {}", err);
+        process::exit(1);
+    });
+
+    match format {
+        "csv" => print!("{}", trajectory.to_csv()),
+        "svg" => print!("{}", trajectory.to_svg(800, 600)),
+        "summary" => {
+            println!(
+                "{} steps over {} units of time",
+                trajectory.len(),
+                trajectory.duration()
+            );
+            if let Some((i, step)) = trajectory.max_depth() {
+                println!(
+                    "Maximum depth {} first reached at step {}, time {} ({:?})",
+                    step.y,
+                    i,
+                    trajectory.time(i),
+                    step
+                );
+            }
+            if let Some(depth) = args.get(3) {
+                let depth = depth.parse::<isize>().unwrap_or_else(|_| usage());
+                match trajectory.first_crossing(depth) {
+                    Some(i) => println!(
+                        "Depth {} first crossed at step {}, time {} ({:?})",
+                        depth,
+                        i,
+                        trajectory.time(i),
+                        trajectory.steps[i]
+                    ),
+                    None => println!("Depth {} is never crossed", depth),
+                }
+            }
+        }
+        _ => usage(),
+    }
+}
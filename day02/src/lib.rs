@@ -0,0 +1,415 @@
+use std::fmt;
+use std::str::FromStr;
+
+/*
+A small interpreter for submarine command files. Each line holds one command and an argument:
+
+    forward X   move forward X units
+    back X      move backward X units
+    down X      go down X units (or increase aim by X)
+    up X        go up X units (or decrease aim by X)
+    hold [N]    stay in place for N steps (default 1)
+
+Anything after a '#' is a comment, and blank lines are ignored.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(isize),
+    Back(isize),
+    Down(isize),
+    Up(isize),
+    Hold(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCommandError {
+    UnknownCommand(String),
+    MissingArgument,
+    InvalidArgument(String),
+    TrailingInput(String),
+}
+
+impl fmt::Display for ParseCommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand(s) => write!(f, "unknown command '{}'", s),
+            Self::MissingArgument => write!(f, "missing argument"),
+            Self::InvalidArgument(s) => write!(f, "invalid argument '{}'", s),
+            Self::TrailingInput(s) => write!(f, "unexpected input '{}'", s),
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseCommandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let name = words.next().ok_or(Self::Err::MissingArgument)?;
+        let arg = words.next();
+        if let Some(extra) = words.next() {
+            return Err(Self::Err::TrailingInput(extra.to_string()));
+        }
+        let distance = || -> Result<isize, Self::Err> {
+            let arg = arg.ok_or(Self::Err::MissingArgument)?;
+            arg.parse()
+                .map_err(|_| Self::Err::InvalidArgument(arg.to_string()))
+        };
+        match name {
+            "forward" => Ok(Command::Forward(distance()?)),
+            "back" => Ok(Command::Back(distance()?)),
+            "down" => Ok(Command::Down(distance()?)),
+            "up" => Ok(Command::Up(distance()?)),
+            "hold" => match arg {
+                None => Ok(Command::Hold(1)),
+                Some(n) => n
+                    .parse()
+                    .map(Command::Hold)
+                    .map_err(|_| Self::Err::InvalidArgument(n.to_string())),
+            },
+            _ => Err(Self::Err::UnknownCommand(name.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    // 1-based line number in the source
+    pub line: usize,
+    pub command: Command,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseProgramError {
+    pub line: usize,
+    pub error: ParseCommandError,
+}
+
+impl fmt::Display for ParseProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn from_lines<S: AsRef<str>>(
+        lines: impl Iterator<Item = S>,
+    ) -> Result<Self, ParseProgramError> {
+        let mut instructions = Vec::new();
+        for (num, line) in lines.enumerate() {
+            let line = line.as_ref();
+            let code = line.split('#').next().unwrap_or_default().trim();
+            if code.is_empty() {
+                continue;
+            }
+            let command = code.parse::<Command>().map_err(|error| ParseProgramError {
+                line: num + 1,
+                error,
+            })?;
+            instructions.push(Instruction {
+                line: num + 1,
+                command,
+            });
+        }
+        Ok(Program { instructions })
+    }
+}
+
+impl FromStr for Program {
+    type Err = ParseProgramError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Program::from_lines(s.lines())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    // Part A: up and down change depth directly
+    Direct,
+    // Part B: up and down change aim, and moving changes depth by aim
+    Aimed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submarine {
+    pub mode: Mode,
+    pub x: isize,
+    pub y: isize,
+    pub aim: isize,
+}
+
+impl Submarine {
+    pub fn new(mode: Mode) -> Self {
+        Submarine {
+            mode,
+            x: 0,
+            y: 0,
+            aim: 0,
+        }
+    }
+
+    // Returns false, leaving the submarine as it was, if the command would take its position or
+    // aim beyond what an isize can hold
+    pub fn apply(&mut self, command: Command) -> bool {
+        let (x, y, aim) = (self.x, self.y, self.aim);
+        let next = match (self.mode, command) {
+            (_, Command::Hold(_)) => Some((x, y, aim)),
+            (Mode::Direct, Command::Forward(d)) => x.checked_add(d).map(|x| (x, y, aim)),
+            (Mode::Direct, Command::Back(d)) => x.checked_sub(d).map(|x| (x, y, aim)),
+            (Mode::Direct, Command::Down(d)) => y.checked_add(d).map(|y| (x, y, aim)),
+            (Mode::Direct, Command::Up(d)) => y.checked_sub(d).map(|y| (x, y, aim)),
+            (Mode::Aimed, Command::Forward(d)) => x
+                .checked_add(d)
+                .zip(aim.checked_mul(d).and_then(|dy| y.checked_add(dy)))
+                .map(|(x, y)| (x, y, aim)),
+            (Mode::Aimed, Command::Back(d)) => x
+                .checked_sub(d)
+                .zip(aim.checked_mul(d).and_then(|dy| y.checked_sub(dy)))
+                .map(|(x, y)| (x, y, aim)),
+            (Mode::Aimed, Command::Down(d)) => aim.checked_add(d).map(|aim| (x, y, aim)),
+            (Mode::Aimed, Command::Up(d)) => aim.checked_sub(d).map(|aim| (x, y, aim)),
+        };
+        match next {
+            Some((x, y, aim)) => {
+                (self.x, self.y, self.aim) = (x, y, aim);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // The current position, as a step produced by the given source line
+    pub fn state(&self, line: Option<usize>) -> Step {
+        Step {
+            line,
+            x: self.x,
+            y: self.y,
+            aim: self.aim,
+            repeat: 1,
+        }
+    }
+
+    // Stops at the first instruction that takes the submarine out of range
+    pub fn run(&mut self, program: &Program) -> Result<(), OutOfRange> {
+        for instruction in program.instructions.iter() {
+            if !self.apply(instruction.command) {
+                return Err(OutOfRange {
+                    line: instruction.line,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+// An instruction that would take the position or aim beyond what an isize can hold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutOfRange {
+    pub line: usize,
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: position or aim out of range", self.line)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunFileError {
+    Parse(ParseProgramError),
+    OutOfRange(OutOfRange),
+}
+
+impl fmt::Display for RunFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => err.fmt(f),
+            Self::OutOfRange(err) => err.fmt(f),
+        }
+    }
+}
+
+pub fn run_file(path: &str, mode: Mode) -> Result<Submarine, RunFileError> {
+    let program = Program::from_lines(app::read_lines(path)).map_err(RunFileError::Parse)?;
+    let mut sub = Submarine::new(mode);
+    sub.run(&program).map_err(RunFileError::OutOfRange)?;
+    Ok(sub)
+}
+
+// The submarine's state after a step, along with the source line of the command that produced it
+// (None for the starting position).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub line: Option<usize>,
+    pub x: isize,
+    pub y: isize,
+    pub aim: isize,
+    // The units of time spent in this state, which is more than 1 for a hold
+    pub repeat: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    pub steps: Vec<Step>,
+}
+
+impl Trajectory {
+    // Runs the program from the surface, recording every position. A hold is recorded as one step
+    // repeated for its length, so long holds take no more room than any other command, and a hold
+    // of 0 isn't recorded at all.
+    pub fn record(mode: Mode, program: &Program) -> Result<Self, OutOfRange> {
+        let mut sub = Submarine::new(mode);
+        let mut steps = vec![sub.state(None)];
+        for instruction in program.instructions.iter() {
+            if !sub.apply(instruction.command) {
+                return Err(OutOfRange {
+                    line: instruction.line,
+                });
+            }
+            let repeat = match instruction.command {
+                Command::Hold(n) => n,
+                _ => 1,
+            };
+            if repeat > 0 {
+                steps.push(Step {
+                    repeat,
+                    ..sub.state(Some(instruction.line))
+                });
+            }
+        }
+        Ok(Trajectory { steps })
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn last(&self) -> Option<&Step> {
+        self.steps.last()
+    }
+
+    // The unit of time at which a step starts
+    pub fn time(&self, index: usize) -> usize {
+        self.steps[..index]
+            .iter()
+            .fold(0, |time, step| time.saturating_add(step.repeat))
+    }
+
+    // The units of time covered by every step
+    pub fn duration(&self) -> usize {
+        self.time(self.steps.len())
+    }
+
+    // The first step reaching the greatest depth
+    pub fn max_depth(&self) -> Option<(usize, &Step)> {
+        self.steps
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, step)| step.y)
+    }
+
+    // The index of the first step that reaches or passes depth, coming from either side, which is
+    // 0 if the trajectory starts at that depth
+    pub fn first_crossing(&self, depth: isize) -> Option<usize> {
+        if self.steps.first()?.y == depth {
+            return Some(0);
+        }
+        self.steps
+            .windows(2)
+            .position(|w| {
+                let (prev, next) = (w[0].y, w[1].y);
+                (prev < depth && next >= depth) || (prev > depth && next <= depth)
+            })
+            .map(|i| i + 1)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,time,repeat,line,x,y,aim\n");
+        let mut time = 0usize;
+        for (i, step) in self.steps.iter().enumerate() {
+            let line = step.line.map(|n| n.to_string()).unwrap_or_default();
+            csv += &format!(
+                "{},{},{},{},{},{},{}\n",
+                i, time, step.repeat, line, step.x, step.y, step.aim
+            );
+            time = time.saturating_add(step.repeat);
+        }
+        csv
+    }
+
+    // Draws the path as a polyline, with depth increasing downwards as in the water. The drawing
+    // is stretched to fill the image, since depths are usually far larger than distances. Each
+    // step is marked with a dot titled with its index and source line.
+    pub fn to_svg(&self, width: usize, height: usize) -> String {
+        let min_x = self.steps.iter().map(|s| s.x).min().unwrap_or(0);
+        let max_x = self.steps.iter().map(|s| s.x).max().unwrap_or(0);
+        let min_y = self.steps.iter().map(|s| s.y).min().unwrap_or(0);
+        let max_y = self.steps.iter().map(|s| s.y).max().unwrap_or(0);
+        // In floating point, since the span of an isize doesn't fit in one
+        let scale_x = width as f64 / (max_x as f64 - min_x as f64).max(1.0);
+        let scale_y = height as f64 / (max_y as f64 - min_y as f64).max(1.0);
+        let project = |s: &Step| {
+            (
+                (s.x as f64 - min_x as f64) * scale_x,
+                (s.y as f64 - min_y as f64) * scale_y,
+            )
+        };
+
+        let points: Vec<String> = self
+            .steps
+            .iter()
+            .map(|s| {
+                let (x, y) = project(s);
+                format!("{:.1},{:.1}", x, y)
+            })
+            .collect();
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+            width, height
+        );
+        svg += &format!(
+            "  <polyline fill=\"none\" stroke=\"black\" points=\"{}\"/>\n",
+            points.join(" ")
+        );
+        for (i, step) in self.steps.iter().enumerate() {
+            let (x, y) = project(step);
+            let line = step
+                .line
+                .map_or_else(|| "start".to_string(), |n| format!("line {}", n));
+            svg += &format!(
+                "  <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"2\"><title>step {} ({}): x={} y={} aim={}</title></circle>\n",
+                x, y, i, line, step.x, step.y, step.aim
+            );
+        }
+        svg += "</svg>\n";
+        svg
+    }
+}
+
+fn product(path: &str, mode: Mode) -> String {
+    let pos = run_file(path, mode).unwrap_or_else(|err| panic!("Invalid program: {}", err));
+    // Wide enough that the product can't overflow
+    (pos.x as i128 * pos.y as i128).to_string()
+}
+
+// The final length times depth, with commands moving the submarine directly
+pub fn part_a(path: &str) -> String {
+    product(path, Mode::Direct)
+}
+
+// The same with up and down changing the aim
+pub fn part_b(path: &str) -> String {
+    product(path, Mode::Aimed)
+}
@@ -0,0 +1,317 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr, Not, Shl, Shr};
+
+/*
+Rows are packed into unsigned integers, with the first character of a row in the most significant
+of its width bits. Column counts are taken by walking the set bits of each row, and the rating
+filters keep the rows sorted so that the rows sharing a prefix form a contiguous range: each filter
+step narrows the range with a binary search instead of copying rows. Filtering from the least
+significant bit works the same way on the rows with their bits reversed.
+*/
+
+pub trait Word:
+    Copy
+    + Ord
+    + fmt::Debug
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + Not<Output = Self>
+    + Shl<usize, Output = Self>
+    + Shr<usize, Output = Self>
+{
+    const BITS: usize;
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn trailing_zeros(self) -> usize;
+    fn reverse_bits(self) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {
+        $(impl Word for $t {
+            const BITS: usize = <$t>::BITS as usize;
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn trailing_zeros(self) -> usize {
+                <$t>::trailing_zeros(self) as usize
+            }
+
+            fn reverse_bits(self) -> Self {
+                <$t>::reverse_bits(self)
+            }
+        })*
+    };
+}
+
+impl_word!(u8, u16, u32, u64, u128);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseReportError {
+    Empty,
+    TooWide(usize),
+    // Line numbers are 1-based
+    WrongWidth {
+        line: usize,
+        expected: usize,
+        actual: usize,
+    },
+    InvalidChar {
+        line: usize,
+        c: char,
+    },
+}
+
+impl fmt::Display for ParseReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no rows in report"),
+            Self::TooWide(width) => write!(f, "rows of {} bits are too wide", width),
+            Self::WrongWidth {
+                line,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "line {}: expected {} bits, found {}",
+                line, expected, actual
+            ),
+            Self::InvalidChar { line, c } => write!(f, "line {}: invalid bit '{}'", line, c),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Commonness {
+    Most,
+    Least,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+// A rule for filtering rows down to a single rating: at each bit, in the given order, keep the
+// rows with the most or least common value, or the tie value if both are equally common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Criterion {
+    pub keep: Commonness,
+    pub tie: bool,
+    pub order: BitOrder,
+}
+
+impl Criterion {
+    pub const O2: Criterion = Criterion {
+        keep: Commonness::Most,
+        tie: true,
+        order: BitOrder::MsbFirst,
+    };
+    pub const CO2: Criterion = Criterion {
+        keep: Commonness::Least,
+        tie: false,
+        order: BitOrder::MsbFirst,
+    };
+
+    pub fn choose(&self, zeros: usize, ones: usize) -> bool {
+        match self.keep {
+            _ if zeros == ones => self.tie,
+            Commonness::Most => ones > zeros,
+            Commonness::Least => ones < zeros,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RatingError {
+    // The criterion chose a value at bit (counted from the least significant) that none of the
+    // remaining rows have
+    Eliminated { bit: usize, value: bool },
+}
+
+impl fmt::Display for RatingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Eliminated { bit, value } => {
+                write!(f, "no remaining rows have {} at bit {}", *value as u8, bit)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticReport<W: Word = u128> {
+    width: usize,
+    // Kept sorted, so rows sharing a prefix are contiguous
+    rows: Vec<W>,
+}
+
+impl<W: Word> DiagnosticReport<W> {
+    // Blank lines are skipped; every other line must have the same width.
+    pub fn from_lines<S: AsRef<str>>(
+        lines: impl Iterator<Item = S>,
+    ) -> Result<Self, ParseReportError> {
+        let mut width = None;
+        let mut rows = Vec::new();
+        for (num, line) in lines.enumerate() {
+            let line = line.as_ref().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let expected = *width.get_or_insert(line.len());
+            if expected > W::BITS {
+                return Err(ParseReportError::TooWide(expected));
+            }
+            if line.len() != expected {
+                return Err(ParseReportError::WrongWidth {
+                    line: num + 1,
+                    expected,
+                    actual: line.len(),
+                });
+            }
+            let row = line.chars().try_fold(W::ZERO, |row, c| match c {
+                '0' => Ok(row << 1),
+                '1' => Ok((row << 1) | W::ONE),
+                _ => Err(ParseReportError::InvalidChar { line: num + 1, c }),
+            })?;
+            rows.push(row);
+        }
+        let width = width.ok_or(ParseReportError::Empty)?;
+        rows.sort_unstable();
+        Ok(DiagnosticReport { width, rows })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    // A value with the low width bits set
+    pub fn mask(&self) -> W {
+        if self.width == W::BITS {
+            !W::ZERO
+        } else {
+            !(!W::ZERO << self.width)
+        }
+    }
+
+    fn bit(&self, row: W, col: usize) -> bool {
+        (row >> col) & W::ONE == W::ONE
+    }
+
+    // Counts the ones in each bit position, indexed by bit (so index 0 is the last column)
+    pub fn ones(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.width];
+        for &row in self.rows.iter() {
+            let mut rest = row;
+            while rest != W::ZERO {
+                let col = rest.trailing_zeros();
+                counts[col] += 1;
+                rest = rest & !(W::ONE << col);
+            }
+        }
+        counts
+    }
+
+    // Returns (gamma, epsilon). Ties count as zero in gamma, and so as one in epsilon.
+    pub fn gamma_epsilon(&self) -> (W, W) {
+        let total = self.rows.len();
+        let gamma = self
+            .ones()
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, ones)| 2 * ones > total)
+            .fold(W::ZERO, |gamma, (col, _)| gamma | (W::ONE << col));
+        (gamma, !gamma & self.mask())
+    }
+
+    fn reverse(&self, row: W) -> W {
+        row.reverse_bits() >> (W::BITS - self.width)
+    }
+
+    // Filters the rows one bit at a time, in the criterion's order, until one row remains. Rows
+    // are visited in sorted order of their bits in that order, so each step narrows a range.
+    pub fn rating(&self, criterion: &Criterion) -> Result<W, RatingError> {
+        let reversed: Vec<W>;
+        let rows = match criterion.order {
+            BitOrder::MsbFirst => &self.rows,
+            BitOrder::LsbFirst => {
+                let mut rows: Vec<W> = self.rows.iter().map(|&row| self.reverse(row)).collect();
+                rows.sort_unstable();
+                reversed = rows;
+                &reversed
+            }
+        };
+
+        let (mut lo, mut hi) = (0, rows.len());
+        for col in (0..self.width).rev() {
+            if hi - lo <= 1 {
+                break;
+            }
+            let split = lo + rows[lo..hi].partition_point(|&row| !self.bit(row, col));
+            let value = criterion.choose(split - lo, hi - split);
+            if value {
+                lo = split;
+            } else {
+                hi = split;
+            }
+            if lo == hi {
+                let bit = match criterion.order {
+                    BitOrder::MsbFirst => col,
+                    BitOrder::LsbFirst => self.width - 1 - col,
+                };
+                return Err(RatingError::Eliminated { bit, value });
+            }
+        }
+        // Any rows left past the last bit are identical
+        Ok(match criterion.order {
+            BitOrder::MsbFirst => rows[lo],
+            BitOrder::LsbFirst => self.reverse(rows[lo]),
+        })
+    }
+
+    pub fn o2_rating(&self) -> Result<W, RatingError> {
+        self.rating(&Criterion::O2)
+    }
+
+    pub fn co2_rating(&self) -> Result<W, RatingError> {
+        self.rating(&Criterion::CO2)
+    }
+}
+
+fn read_report(path: &str) -> DiagnosticReport {
+    DiagnosticReport::from_lines(app::read_lines(path))
+        .unwrap_or_else(|err| panic!("Invalid report: {}", err))
+}
+
+// Gamma times epsilon
+pub fn part_a(path: &str) -> String {
+    let (gamma, epsilon) = read_report(path).gamma_epsilon();
+    gamma
+        .checked_mul(epsilon)
+        .expect("Product is too large")
+        .to_string()
+}
+
+// The O2 rating times the CO2 rating
+pub fn part_b(path: &str) -> String {
+    let report = read_report(path);
+    let o2 = report
+        .o2_rating()
+        .unwrap_or_else(|err| panic!("Unable to find rating: {}", err));
+    let co2 = report
+        .co2_rating()
+        .unwrap_or_else(|err| panic!("Unable to find rating: {}", err));
+    o2.checked_mul(co2)
+        .expect("Product is too large")
+        .to_string()
+}
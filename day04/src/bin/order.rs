@@ -0,0 +1,39 @@
+use std::env;
+use std::process;
+
+use day04::Game;
+
+// Prints every card in the order it wins: order [--diagonals] [FILE]
+fn main() {
+    let mut diagonals = false;
+    let mut path = "input.txt".to_string();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--diagonals" => diagonals = true,
+            _ => path = arg,
+        }
+    }
+
+    let rows: Vec<String> = app::read_lines(&path).collect();
+    let mut game = Game::from_lines(&rows).unwrap_or_else(|err| {
+        eprintln!("Invalid game: {}", err);
+        process::exit(1);
+    });
+    game.diagonals = diagonals;
+
+    let order = game.finishing_order();
+    for (place, finish) in order.iter().enumerate() {
+        println!(
+            "{}: card {} wins on draw {} ({}) with score {}",
+            place + 1,
+            finish.card + 1,
+            finish.draw + 1,
+            finish.value,
+            finish.score
+        );
+    }
+    let never = game.cards.len() - order.len();
+    if never > 0 {
+        println!("{} cards never win", never);
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+use std::fmt;
+
+pub type Value = u32;
+
+/*
+Each card indexes the cells holding each value and counts the marked cells in every row, column,
+and diagonal, so marking a value touches only the cells holding it and checking for a win doesn't
+rescan the card. The game likewise indexes which cards hold each value, so each draw only visits
+those cards.
+*/
+
+#[derive(Debug, Clone, Default)]
+pub struct BingoCard {
+    width: usize,
+    height: usize,
+    cells: Vec<Value>,
+    called: Vec<bool>,
+    // Indexes of the cells holding each value
+    positions: HashMap<Value, Vec<usize>>,
+    row_hits: Vec<usize>,
+    col_hits: Vec<usize>,
+    // Main diagonal, then anti-diagonal; only counted on square cards
+    diag_hits: [usize; 2],
+    unmarked_sum: u64,
+    line_complete: bool,
+    diagonal_complete: bool,
+}
+
+impl BingoCard {
+    pub fn new(width: usize, height: usize) -> BingoCard {
+        BingoCard::from_cells(width, height, vec![0; width * height])
+    }
+
+    fn from_cells(width: usize, height: usize, cells: Vec<Value>) -> BingoCard {
+        let mut positions: HashMap<Value, Vec<usize>> = HashMap::new();
+        for (i, &cell) in cells.iter().enumerate() {
+            positions.entry(cell).or_default().push(i);
+        }
+        BingoCard {
+            width,
+            height,
+            called: vec![false; cells.len()],
+            unmarked_sum: cells.iter().map(|&cell| cell as u64).sum(),
+            cells,
+            positions,
+            row_hits: vec![0; height],
+            col_hits: vec![0; width],
+            diag_hits: [0; 2],
+            line_complete: false,
+            diagonal_complete: false,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Value {
+        self.cells[self.width * y + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, val: Value) {
+        let i = self.width * y + x;
+        let old = self.cells[i];
+        if let Some(cells) = self.positions.get_mut(&old) {
+            cells.retain(|&j| j != i);
+            if cells.is_empty() {
+                self.positions.remove(&old);
+            }
+        }
+        self.positions.entry(val).or_default().push(i);
+        if !self.called[i] {
+            self.unmarked_sum = self.unmarked_sum - old as u64 + val as u64;
+        }
+        self.cells[i] = val;
+    }
+
+    pub fn contains(&self, val: Value) -> bool {
+        self.positions.contains_key(&val)
+    }
+
+    // The distinct values on the card
+    pub fn values(&self) -> impl Iterator<Item = Value> + '_ {
+        self.positions.keys().copied()
+    }
+
+    pub fn is_marked(&self, x: usize, y: usize) -> bool {
+        self.called[self.width * y + x]
+    }
+
+    pub fn mark(&mut self, x: usize, y: usize) {
+        self.mark_cell(self.width * y + x);
+    }
+
+    fn mark_cell(&mut self, i: usize) {
+        if self.called[i] {
+            return;
+        }
+        self.called[i] = true;
+        self.unmarked_sum -= self.cells[i] as u64;
+
+        let (x, y) = (i % self.width, i / self.width);
+        self.row_hits[y] += 1;
+        self.col_hits[x] += 1;
+        if self.row_hits[y] == self.width || self.col_hits[x] == self.height {
+            self.line_complete = true;
+        }
+        if self.width == self.height {
+            for (diag, on_diag) in [x == y, x + y + 1 == self.width].into_iter().enumerate() {
+                if on_diag {
+                    self.diag_hits[diag] += 1;
+                    if self.diag_hits[diag] == self.width {
+                        self.diagonal_complete = true;
+                    }
+                }
+            }
+        }
+    }
+
+    // Marks every cell holding val, returning whether there were any
+    pub fn mark_value(&mut self, val: Value) -> bool {
+        // Taken out while marking, since marking needs the card mutably
+        let Some(cells) = self.positions.remove(&val) else {
+            return false;
+        };
+        for &i in cells.iter() {
+            self.mark_cell(i);
+        }
+        self.positions.insert(val, cells);
+        true
+    }
+
+    pub fn unmarked_sum(&self) -> u64 {
+        self.unmarked_sum
+    }
+
+    // Wide enough that an unmarked sum times a value can't overflow
+    pub fn score(&self, val: Value) -> u128 {
+        self.unmarked_sum as u128 * val as u128
+    }
+
+    // Diagonals only exist on square cards
+    pub fn wins(&self, diagonals: bool) -> bool {
+        self.line_complete || (diagonals && self.diagonal_complete)
+    }
+
+    // Each line is a row of whitespace-separated values; first_line is the line number of the
+    // first row, for error reporting.
+    pub fn from_lines<S: AsRef<str>>(
+        lines: &[S],
+        first_line: usize,
+    ) -> Result<BingoCard, ParseGameError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        for (j, line) in lines.iter().enumerate() {
+            let line_num = first_line + j;
+            let row = line
+                .as_ref()
+                .split_whitespace()
+                .map(|v| parse_value(v, line_num))
+                .collect::<Result<Vec<_>, _>>()?;
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(ParseGameError::RaggedCard {
+                    line: line_num,
+                    expected,
+                    actual: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+        Ok(BingoCard::from_cells(
+            width.unwrap_or(0),
+            lines.len(),
+            cells,
+        ))
+    }
+}
+
+// Line numbers are 1-based
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGameError {
+    MissingDraws,
+    NoCards,
+    BadValue {
+        line: usize,
+        value: String,
+    },
+    RaggedCard {
+        line: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for ParseGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDraws => write!(f, "missing line of drawn numbers"),
+            Self::NoCards => write!(f, "no cards"),
+            Self::BadValue { line, value } => write!(f, "line {}: invalid value '{}'", line, value),
+            Self::RaggedCard {
+                line,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "line {}: expected {} values, found {}",
+                line, expected, actual
+            ),
+        }
+    }
+}
+
+fn parse_value(s: &str, line: usize) -> Result<Value, ParseGameError> {
+    s.trim().parse().map_err(|_| ParseGameError::BadValue {
+        line,
+        value: s.to_string(),
+    })
+}
+
+// A card that has won, in the order cards finish
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Finish {
+    pub card: usize,
+    // Index into the draws of the winning draw
+    pub draw: usize,
+    pub value: Value,
+    pub score: u128,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Game {
+    pub draws: Vec<Value>,
+    pub cards: Vec<BingoCard>,
+    pub diagonals: bool,
+}
+
+impl Game {
+    // The first non-blank line holds the comma-separated draws, followed by cards separated by
+    // any number of blank lines.
+    pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Result<Game, ParseGameError> {
+        let mut numbered = lines
+            .iter()
+            .map(|l| l.as_ref())
+            .enumerate()
+            .skip_while(|(_, l)| l.trim().is_empty());
+        let (draw_line, draws) = numbered.next().ok_or(ParseGameError::MissingDraws)?;
+        let draws = draws
+            .split(',')
+            .map(|v| parse_value(v, draw_line + 1))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut cards = Vec::new();
+        let first = draw_line + 1;
+        let mut start = first;
+        for i in first..=lines.len() {
+            let blank = lines.get(i).is_none_or(|l| l.as_ref().trim().is_empty());
+            if blank {
+                if i > start {
+                    cards.push(BingoCard::from_lines(&lines[start..i], start + 1)?);
+                }
+                start = i + 1;
+            }
+        }
+        if cards.is_empty() {
+            return Err(ParseGameError::NoCards);
+        }
+
+        Ok(Game {
+            draws,
+            cards,
+            diagonals: false,
+        })
+    }
+
+    // Plays every draw, returning each card that wins in the order they win. Cards winning on
+    // the same draw are in card order; cards that never win are left out.
+    pub fn finishing_order(&self) -> Vec<Finish> {
+        let mut holders: HashMap<Value, Vec<usize>> = HashMap::new();
+        for (i, card) in self.cards.iter().enumerate() {
+            for value in card.values() {
+                holders.entry(value).or_default().push(i);
+            }
+        }
+        // Values are visited in arbitrary order, so restore card order
+        for cards in holders.values_mut() {
+            cards.sort_unstable();
+        }
+
+        let mut cards = self.cards.clone();
+        let mut finished = vec![false; cards.len()];
+        let mut order = Vec::new();
+        for (draw, &value) in self.draws.iter().enumerate() {
+            for &i in holders.get(&value).into_iter().flatten() {
+                let card = &mut cards[i];
+                if !finished[i] && card.mark_value(value) && card.wins(self.diagonals) {
+                    finished[i] = true;
+                    order.push(Finish {
+                        card: i,
+                        draw,
+                        value,
+                        score: card.score(value),
+                    });
+                }
+            }
+            if order.len() == cards.len() {
+                break;
+            }
+        }
+        order
+    }
+}
+
+fn finishing_order(path: &str) -> Vec<Finish> {
+    let rows: Vec<String> = app::read_lines(path).collect();
+    let game = Game::from_lines(&rows).unwrap_or_else(|err| panic!("Invalid game: {}", err));
+    game.finishing_order()
+}
+
+// The score of the first card to win
+pub fn part_a(path: &str) -> String {
+    let order = finishing_order(path);
+    order.first().expect("No card wins").score.to_string()
+}
+
+// The score of the last card to win
+pub fn part_b(path: &str) -> String {
+    let order = finishing_order(path);
+    order.last().expect("No card wins").score.to_string()
+}
@@ -0,0 +1,83 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use day05::{Grid, Point, Rect, Segment};
+
+const USAGE: &str = "Usage: map [--rect X1,Y1,X2,Y2] [--pgm FILE] [--top N] [--at X,Y] [FILE]";
+
+// Larger maps are only drawn as images
+const MAX_TEXT_SIZE: usize = 100;
+// Larger maps aren't drawn at all
+const MAX_IMAGE_SIZE: usize = 4096;
+
+fn parse_rect(s: &str) -> Option<Rect> {
+    let ns: Vec<isize> = s
+        .split(',')
+        .map(|n| n.parse().ok())
+        .collect::<Option<_>>()?;
+    match ns[..] {
+        [x1, y1, x2, y2] => Some(Rect::new(Point::new(x1, y1), Point::new(x2, y2))),
+        _ => None,
+    }
+}
+
+fn main() {
+    let usage = |err: &str| -> ! {
+        eprintln!("{}\n{}", err, USAGE);
+        process::exit(2);
+    };
+    let mut path = "input.txt".to_string();
+    let mut rect = None;
+    let mut pgm = None;
+    let mut top = None;
+    let mut at = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage("Missing value"));
+        match arg.as_str() {
+            "--rect" => rect = Some(parse_rect(&value()).unwrap_or_else(|| usage("Bad rect"))),
+            "--pgm" => pgm = Some(value()),
+            "--top" => top = Some(value().parse().unwrap_or_else(|_| usage("Bad count"))),
+            "--at" => {
+                at = Some(
+                    value()
+                        .parse::<Point>()
+                        .unwrap_or_else(|_| usage("Bad point")),
+                )
+            }
+            _ => path = arg,
+        }
+    }
+
+    let lines: Vec<Segment> = app::read_lines(&path)
+        .map(|line| line.parse::<Segment>().unwrap())
+        .collect();
+    let grid = Grid::new(lines);
+    let Some(rect) = rect.or_else(|| grid.bounds()) else {
+        println!("No lines to map");
+        return;
+    };
+    if pgm.is_some() && (rect.width() > MAX_IMAGE_SIZE || rect.height() > MAX_IMAGE_SIZE) {
+        usage("Map is too large to draw as an image, use a smaller --rect");
+    }
+
+    if let Some(n) = top {
+        for (p, count) in grid.densest(&rect, n) {
+            println!("{},{} is covered by {} lines", p.x, p.y, count);
+        }
+    }
+    if let Some(p) = at {
+        for line in grid.lines_through(p) {
+            println!("{},{} -> {},{}", line.p1.x, line.p1.y, line.p2.x, line.p2.y);
+        }
+    }
+    if let Some(pgm) = pgm {
+        fs::write(&pgm, grid.heatmap(&rect)).expect("Unable to write image");
+    } else if top.is_none() && at.is_none() {
+        if rect.width() > MAX_TEXT_SIZE || rect.height() > MAX_TEXT_SIZE {
+            usage("Map is too large to draw as text, use --pgm or a smaller --rect");
+        }
+        print!("{}", grid.render(&rect));
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use app::point::{ParsePointError, Point2D};
+
+pub mod overlap;
+
+use overlap::Direction;
+
+pub type Point = Point2D<isize>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSegmentError {
+    WrongNumPoints(usize),
+    BadPoint(ParsePointError),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub p1: Point,
+    pub p2: Point,
+}
+
+pub struct Grid {
+    lines: Vec<Segment>,
+}
+
+// An inclusive rectangle of cells
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    // Any two opposite corners
+    pub fn new(a: Point, b: Point) -> Rect {
+        Rect {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y) as usize + 1
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+}
+
+// The range of i for which start + i * step lies within lo..=hi, if any
+fn steps_within(start: isize, step: isize, lo: isize, hi: isize) -> Option<(isize, isize)> {
+    match step {
+        0 if (lo..=hi).contains(&start) => Some((isize::MIN, isize::MAX)),
+        0 => None,
+        _ if step > 0 => Some((div_ceil(lo - start, step), (hi - start).div_euclid(step))),
+        _ => Some((div_ceil(start - hi, -step), (start - lo).div_euclid(-step))),
+    }
+}
+
+fn div_ceil(a: isize, b: isize) -> isize {
+    -(-a).div_euclid(b)
+}
+
+pub fn gcd(a: isize, b: isize) -> isize {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Segment {
+    // The step from each lattice point on the segment to the next, from p1 to p2, or (0, 0) if
+    // the segment is a single point
+    pub fn slope(&self) -> (isize, isize) {
+        let dx = self.p2.x - self.p1.x;
+        let dy = self.p2.y - self.p1.y;
+        match gcd(dx, dy) {
+            0 => (0, 0),
+            g => (dx / g, dy / g),
+        }
+    }
+
+    // The number of lattice points on the segment
+    pub fn num_points(&self) -> usize {
+        gcd(self.p2.x - self.p1.x, self.p2.y - self.p1.y) as usize + 1
+    }
+
+    pub fn is_axis_aligned(&self) -> bool {
+        match self.slope() {
+            (0, 0) => false,
+            (0, _) | (_, 0) => true,
+            _ => false,
+        }
+    }
+
+    // A segment that is a single point has no direction
+    pub fn direction(&self) -> Option<Direction> {
+        let (dx, dy) = self.slope();
+        Direction::new(dx, dy)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        let (dx, dy) = (self.p2.x - self.p1.x, self.p2.y - self.p1.y);
+        let (px, py) = (p.x - self.p1.x, p.y - self.p1.y);
+        let collinear = dx as i128 * py as i128 == dy as i128 * px as i128;
+        collinear && Rect::new(self.p1, self.p2).contains(p)
+    }
+
+    // The lattice points of the segment that lie within rect, without visiting the others
+    pub fn points_within(&self, rect: &Rect) -> impl Iterator<Item = Point> {
+        let (dx, dy) = self.slope();
+        let p1 = self.p1;
+        let (x_lo, x_hi) = steps_within(p1.x, dx, rect.min.x, rect.max.x).unwrap_or((1, 0));
+        let (y_lo, y_hi) = steps_within(p1.y, dy, rect.min.y, rect.max.y).unwrap_or((1, 0));
+        let lo = x_lo.max(y_lo).max(0);
+        let hi = x_hi.min(y_hi).min(self.num_points() as isize - 1);
+        (lo..=hi).map(move |i| Point::new(p1.x + i * dx, p1.y + i * dy))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (dx, dy) = self.slope();
+        let p1 = self.p1;
+        (0..self.num_points() as isize).map(move |i| Point::new(p1.x + i * dx, p1.y + i * dy))
+    }
+}
+
+impl FromStr for Segment {
+    type Err = ParseSegmentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ps = s
+            .split(" -> ")
+            .map(|p| p.parse::<Point>().map_err(Self::Err::BadPoint));
+        let p1 = ps.next().unwrap_or(Err(Self::Err::WrongNumPoints(0)))?;
+        let p2 = ps.next().unwrap_or(Err(Self::Err::WrongNumPoints(1)))?;
+        match ps.count() {
+            0 => Ok(Segment { p1, p2 }),
+            n => Err(Self::Err::WrongNumPoints(n + 2)),
+        }
+    }
+}
+
+impl Grid {
+    pub fn new(lines: Vec<Segment>) -> Grid {
+        Grid { lines }
+    }
+
+    pub fn num_lines(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn coverage(&self) -> HashMap<Point, usize> {
+        let mut map = HashMap::new();
+        let ps = self.lines.iter().flat_map(|l| l.points());
+        for p in ps {
+            let count = map.entry(p).or_default();
+            *count += 1;
+        }
+        map
+    }
+
+    // The number of points covered by more than one line
+    pub fn num_collisions(&self) -> usize {
+        overlap::count_overlaps(&self.lines)
+    }
+
+    pub fn lines(&self) -> &[Segment] {
+        &self.lines
+    }
+
+    // The smallest rectangle containing every line
+    pub fn bounds(&self) -> Option<Rect> {
+        let mut ends = self.lines.iter().flat_map(|l| [l.p1, l.p2]);
+        let first = ends.next()?;
+        Some(ends.fold(Rect::new(first, first), |rect, p| Rect {
+            min: Point::new(rect.min.x.min(p.x), rect.min.y.min(p.y)),
+            max: Point::new(rect.max.x.max(p.x), rect.max.y.max(p.y)),
+        }))
+    }
+
+    // The n most covered cells of rect, most covered first, then in reading order. Cells are
+    // never rasterised, so rect can be as large as the lines.
+    pub fn densest(&self, rect: &Rect, n: usize) -> Vec<(Point, usize)> {
+        overlap::densest(&self.lines, rect, n)
+    }
+
+    // The number of lines covering each cell of rect, indexed by row (y) then column (x) relative
+    // to the rectangle's corner. Only the points of each line inside rect are visited.
+    pub fn coverage_within(&self, rect: &Rect) -> app::grid::Grid<usize> {
+        let mut cells = app::grid::Grid::new(rect.height(), rect.width());
+        for p in self.lines.iter().flat_map(|l| l.points_within(rect)) {
+            cells[(p.y - rect.min.y) as usize][(p.x - rect.min.x) as usize] += 1;
+        }
+        cells
+    }
+
+    pub fn lines_through(&self, p: Point) -> Vec<&Segment> {
+        self.lines.iter().filter(|l| l.contains(p)).collect()
+    }
+
+    // Draws the coverage as in the puzzle's example: '.' for no lines, otherwise the number of
+    // lines, or '*' for more than nine.
+    pub fn render(&self, rect: &Rect) -> String {
+        let cells = self.coverage_within(rect);
+        let (rows, cols) = cells.size();
+        let mut s = String::with_capacity(rows * (cols + 1));
+        for y in 0..rows {
+            for &count in cells[y].iter() {
+                s.push(match count {
+                    0 => '.',
+                    1..=9 => char::from_digit(count as u32, 10).unwrap(),
+                    _ => '*',
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    // Draws the coverage as a binary greyscale netpbm (PGM) image, from black for no lines to
+    // white for the most covered cells.
+    pub fn heatmap(&self, rect: &Rect) -> Vec<u8> {
+        let cells = self.coverage_within(rect);
+        let (rows, cols) = cells.size();
+        let max = cells
+            .iter()
+            .map(|(_, _, &count)| count)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let mut image = format!("P5\n{} {}\n255\n", cols, rows).into_bytes();
+        image.extend(cells.iter().map(|(_, _, &count)| (count * 255 / max) as u8));
+        image
+    }
+}
+
+fn read_segments(path: &str) -> Vec<Segment> {
+    app::read_lines(path)
+        .map(|line| {
+            line.parse::<Segment>()
+                .unwrap_or_else(|err| panic!("Invalid segment {}: {:?}", line, err))
+        })
+        .collect()
+}
+
+// Points covered more than once by horizontal and vertical segments
+pub fn part_a(path: &str) -> String {
+    let segments = read_segments(path);
+    let grid = Grid::new(
+        segments
+            .into_iter()
+            .filter(Segment::is_axis_aligned)
+            .collect(),
+    );
+    grid.num_collisions().to_string()
+}
+
+// Points covered more than once by any segments
+pub fn part_b(path: &str) -> String {
+    Grid::new(read_segments(path)).num_collisions().to_string()
+}
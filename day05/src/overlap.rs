@@ -0,0 +1,390 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::{Point, Rect, Segment};
+
+/*
+Counts the points covered by at least two segments without visiting the points themselves.
+
+Segments in the same direction are parallel, so they can only overlap if they lie on the same line,
+where their overlap is found by sweeping over interval endpoints. This gives, for each direction,
+the intervals covered by one or more segments and those covered by two or more. Segments in
+different directions meet in at most one point, and every such point is found by sweeping across
+one direction's lines while tracking which lines of the other direction are active.
+
+A point is overlapped if two segments of the same direction cover it, or if lines of two
+directions cover it. The second kind are exactly the crossing points, so the count is the number of
+distinct crossings plus the points overlapped within a direction that aren't also crossings.
+
+The same layers give the number of segments covering any point: away from the crossings, it's the
+number of segments of the point's own direction that cover it, which is constant along runs of each
+line. So the most covered points are found among the crossings and the first few points of each
+run, again without visiting the rest.
+*/
+
+// The step between neighbouring lattice points on a line, with dx and dy coprime, and normalised
+// to point right (or down, for vertical lines). Along with the step, (u, v) satisfy
+// u * dx + v * dy = 1, which gives each lattice point a position along its line.
+//
+// Lines and positions are computed in i128, so coordinates and steps up to about 2^40 are exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Direction {
+    pub dx: isize,
+    pub dy: isize,
+    u: isize,
+    v: isize,
+}
+
+impl Direction {
+    pub const HORIZONTAL: Direction = Direction {
+        dx: 1,
+        dy: 0,
+        u: 1,
+        v: 0,
+    };
+    pub const VERTICAL: Direction = Direction {
+        dx: 0,
+        dy: 1,
+        u: 0,
+        v: 1,
+    };
+
+    // Returns None if dx and dy are both zero
+    pub fn new(dx: isize, dy: isize) -> Option<Direction> {
+        if dx == 0 && dy == 0 {
+            return None;
+        }
+        let g = crate::gcd(dx, dy);
+        let (mut dx, mut dy) = (dx / g, dy / g);
+        if dx < 0 || (dx == 0 && dy < 0) {
+            dx = -dx;
+            dy = -dy;
+        }
+        let (u, v) = bezout(dx, dy);
+        Some(Direction { dx, dy, u, v })
+    }
+
+    // Identifies the line in this direction through p
+    pub fn line(self, p: Point) -> i128 {
+        self.dy as i128 * p.x as i128 - self.dx as i128 * p.y as i128
+    }
+
+    // The position of p along its line, which increases by one from each lattice point to the next
+    pub fn pos(self, p: Point) -> i128 {
+        self.u as i128 * p.x as i128 + self.v as i128 * p.y as i128
+    }
+
+    // Inverts (line, pos), since the matrix [dy -dx; u v] has determinant 1
+    pub fn point_at(self, line: i128, pos: i128) -> (i128, i128) {
+        (
+            self.v as i128 * line + self.dx as i128 * pos,
+            self.dy as i128 * pos - self.u as i128 * line,
+        )
+    }
+
+    // The lattice point where line a of this direction meets line b of another direction, found
+    // by Cramer's rule. Parallel lines never meet, and other lines may cross between lattice
+    // points.
+    pub fn meet(self, a: i128, other: Direction, b: i128) -> Option<Point> {
+        let (dx1, dy1) = (self.dx as i128, self.dy as i128);
+        let (dx2, dy2) = (other.dx as i128, other.dy as i128);
+        let det = dx1 * dy2 - dx2 * dy1;
+        if det == 0 {
+            return None;
+        }
+        let x = b * dx1 - a * dx2;
+        let y = b * dy1 - a * dy2;
+        if x % det != 0 || y % det != 0 {
+            return None;
+        }
+        Some(Point::new(
+            isize::try_from(x / det).ok()?,
+            isize::try_from(y / det).ok()?,
+        ))
+    }
+}
+
+// Solves u * a + v * b = 1 for coprime a and b with the extended Euclidean algorithm
+fn bezout(a: isize, b: isize) -> (isize, isize) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut u0, mut u1) = (1, 0);
+    let (mut v0, mut v1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (u0, u1) = (u1, u0 - q * u1);
+        (v0, v1) = (v1, v0 - q * v1);
+    }
+    // The gcd may come out as -1
+    (u0 * r0, v0 * r0)
+}
+
+// Inclusive ranges of positions, sorted and disjoint, keyed by line
+type Lines = BTreeMap<i128, Vec<(i128, i128)>>;
+
+#[derive(Debug, Clone, Default)]
+struct Layers {
+    covered: Lines,
+    overlapped: Lines,
+    // Inclusive ranges of positions covered by the same number of segments, with that number
+    depths: BTreeMap<i128, Vec<(i128, i128, usize)>>,
+}
+
+impl Layers {
+    fn new(spans: HashMap<i128, Vec<(i128, i128)>>) -> Self {
+        let mut layers = Layers::default();
+        for (line, spans) in spans {
+            let mut events: Vec<(i128, i128)> = spans
+                .iter()
+                .flat_map(|&(lo, hi)| [(lo, 1), (hi + 1, -1)])
+                .collect();
+            events.sort_unstable();
+            layers.covered.insert(line, runs(&events, 1));
+            let overlapped = runs(&events, 2);
+            if !overlapped.is_empty() {
+                layers.overlapped.insert(line, overlapped);
+            }
+            layers.depths.insert(line, levels(&events));
+        }
+        layers
+    }
+
+    fn num_overlapped(&self) -> usize {
+        self.overlapped
+            .values()
+            .flatten()
+            .map(|&(lo, hi)| (hi - lo + 1) as usize)
+            .sum()
+    }
+
+    fn is_overlapped(&self, line: i128, pos: i128) -> bool {
+        self.overlapped.get(&line).is_some_and(|spans| {
+            let i = spans.partition_point(|&(_, hi)| hi < pos);
+            spans.get(i).is_some_and(|&(lo, _)| lo <= pos)
+        })
+    }
+
+    // The number of segments covering a position of a line
+    fn depth(&self, line: i128, pos: i128) -> usize {
+        self.depths.get(&line).map_or(0, |levels| {
+            let i = levels.partition_point(|&(_, hi, _)| hi < pos);
+            match levels.get(i) {
+                Some(&(lo, _, depth)) if lo <= pos => depth,
+                _ => 0,
+            }
+        })
+    }
+}
+
+// The ranges where the running sum of the (position, delta) events is at least depth
+fn runs(events: &[(i128, i128)], depth: i128) -> Vec<(i128, i128)> {
+    let mut ranges = Vec::new();
+    let mut sum = 0;
+    let mut start = None;
+    let mut i = 0;
+    while i < events.len() {
+        let pos = events[i].0;
+        while i < events.len() && events[i].0 == pos {
+            sum += events[i].1;
+            i += 1;
+        }
+        match start {
+            None if sum >= depth => start = Some(pos),
+            Some(lo) if sum < depth => {
+                ranges.push((lo, pos - 1));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    ranges
+}
+
+// The ranges where the running sum of the (position, delta) events is positive, with that sum
+fn levels(events: &[(i128, i128)]) -> Vec<(i128, i128, usize)> {
+    let mut ranges = Vec::new();
+    let mut sum = 0;
+    let mut i = 0;
+    while i < events.len() {
+        let pos = events[i].0;
+        while i < events.len() && events[i].0 == pos {
+            sum += events[i].1;
+            i += 1;
+        }
+        // Every range that's opened is closed by a later event
+        if let (true, Some(&(next, _))) = (sum > 0, events.get(i)) {
+            ranges.push((pos, next - 1, sum as usize));
+        }
+    }
+    ranges
+}
+
+// A covered range of line `line` in direction `dir`, given as the range of lines of another
+// direction it passes through
+fn span_across(
+    dir: Direction,
+    line: i128,
+    (lo, hi): (i128, i128),
+    other: Direction,
+) -> (i128, i128) {
+    let across = |pos| {
+        let (x, y) = dir.point_at(line, pos);
+        other.dy as i128 * x - other.dx as i128 * y
+    };
+    let (a, b) = (across(lo), across(hi));
+    (a.min(b), a.max(b))
+}
+
+// Finds every pair of a row (r, lo, hi), spanning columns lo to hi, and a column (c, lo, hi),
+// spanning rows lo to hi, that cross, as (r, c). Sweeps across columns, tracking the active rows.
+fn crossings(rows: &[(i128, i128, i128)], cols: &[(i128, i128, i128)]) -> Vec<(i128, i128)> {
+    // Rows are added before and removed after the columns at the same position
+    const ADD: u8 = 0;
+    const QUERY: u8 = 1;
+    const REMOVE: u8 = 2;
+    let mut events: Vec<(i128, u8, usize)> = Vec::with_capacity(2 * rows.len() + cols.len());
+    for (i, &(_, lo, hi)) in rows.iter().enumerate() {
+        events.push((lo, ADD, i));
+        events.push((hi, REMOVE, i));
+    }
+    for (i, &(c, _, _)) in cols.iter().enumerate() {
+        events.push((c, QUERY, i));
+    }
+    events.sort_unstable();
+
+    let mut active: BTreeMap<i128, usize> = BTreeMap::new();
+    let mut found = Vec::new();
+    for (_, kind, i) in events {
+        match kind {
+            ADD => *active.entry(rows[i].0).or_default() += 1,
+            REMOVE => {
+                let r = rows[i].0;
+                if let Some(n) = active.get_mut(&r) {
+                    *n -= 1;
+                    if *n == 0 {
+                        active.remove(&r);
+                    }
+                }
+            }
+            _ => {
+                let (c, lo, hi) = cols[i];
+                found.extend(active.range(lo..=hi).map(|(&r, _)| (r, c)));
+            }
+        }
+    }
+    found
+}
+
+// Groups the segments by direction and line, and sweeps each line
+fn layers(segments: &[Segment]) -> Vec<(Direction, Layers)> {
+    let mut spans: BTreeMap<Direction, HashMap<i128, Vec<(i128, i128)>>> = BTreeMap::new();
+    for segment in segments {
+        // A single point can be given any direction
+        let dir = segment.direction().unwrap_or(Direction::HORIZONTAL);
+        let (a, b) = (dir.pos(segment.p1), dir.pos(segment.p2));
+        spans
+            .entry(dir)
+            .or_default()
+            .entry(dir.line(segment.p1))
+            .or_default()
+            .push((a.min(b), a.max(b)));
+    }
+    spans
+        .into_iter()
+        .map(|(dir, spans)| (dir, Layers::new(spans)))
+        .collect()
+}
+
+// Every lattice point where segments of two directions meet, each once
+fn crossing_points(layers: &[(Direction, Layers)]) -> Vec<Point> {
+    let mut crossed = Vec::new();
+    for (i, (d1, l1)) in layers.iter().enumerate() {
+        for (d2, l2) in layers[i + 1..].iter() {
+            let across = |dir: Direction, lines: &Lines, other: Direction| {
+                lines
+                    .iter()
+                    .flat_map(|(&line, ranges)| {
+                        ranges.iter().map(move |&range| {
+                            let (lo, hi) = span_across(dir, line, range, other);
+                            (line, lo, hi)
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let rows = across(*d1, &l1.covered, *d2);
+            let cols = across(*d2, &l2.covered, *d1);
+            crossed.extend(
+                crossings(&rows, &cols)
+                    .into_iter()
+                    .filter_map(|(a, b)| d1.meet(a, *d2, b))
+                    .map(|p| (p.x, p.y)),
+            );
+        }
+    }
+    crossed.sort_unstable();
+    crossed.dedup();
+    crossed.into_iter().map(|(x, y)| Point::new(x, y)).collect()
+}
+
+pub fn count_overlaps(segments: &[Segment]) -> usize {
+    let layers = layers(segments);
+    let crossed = crossing_points(&layers);
+    let overlapped: usize = layers.iter().map(|(_, l)| l.num_overlapped()).sum();
+    let both = crossed
+        .iter()
+        .flat_map(|&p| {
+            layers
+                .iter()
+                .filter(move |(dir, l)| l.is_overlapped(dir.line(p), dir.pos(p)))
+        })
+        .count();
+    crossed.len() + overlapped - both
+}
+
+// The n most covered points of rect, most covered first, then in reading order, with the number of
+// segments covering each
+pub fn densest(segments: &[Segment], rect: &Rect, n: usize) -> Vec<(Point, usize)> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let layers = layers(segments);
+    let crossed: HashSet<Point> = crossing_points(&layers)
+        .into_iter()
+        .filter(|&p| rect.contains(p))
+        .collect();
+    let mut cells: Vec<(Point, usize)> = crossed
+        .iter()
+        .map(|&p| {
+            let count = layers
+                .iter()
+                .map(|(dir, l)| l.depth(dir.line(p), dir.pos(p)))
+                .sum();
+            (p, count)
+        })
+        .collect();
+    for (dir, l) in layers.iter() {
+        for (&line, levels) in l.depths.iter() {
+            for &(lo, hi, depth) in levels {
+                let end = |pos| {
+                    let (x, y) = dir.point_at(line, pos);
+                    Point::new(x as isize, y as isize)
+                };
+                // Positions run against reading order on lines that rise to the right
+                let (p1, p2) = match dir.dy < 0 {
+                    true => (end(hi), end(lo)),
+                    false => (end(lo), end(hi)),
+                };
+                let run = Segment { p1, p2 };
+                cells.extend(
+                    run.points_within(rect)
+                        .filter(|p| !crossed.contains(p))
+                        .take(n)
+                        .map(|p| (p, depth)),
+                );
+            }
+        }
+    }
+    cells.sort_unstable_by_key(|&(p, count)| (Reverse(count), p.y, p.x));
+    cells.truncate(n);
+    cells
+}
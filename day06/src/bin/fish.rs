@@ -0,0 +1,48 @@
+use std::env;
+use std::process;
+
+use day06::{Exact, Modulo};
+
+const USAGE: &str = "Usage: fish DAYS [--mod M] [FILE]";
+
+// Counts the fish after any number of days, exactly or modulo M
+fn main() {
+    let usage = || -> ! {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let mut days = None;
+    let mut modulus = None;
+    let mut path = "input.txt".to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--mod" => {
+                modulus = match args.next().and_then(|m| m.parse::<u64>().ok()) {
+                    Some(m) => Some(Modulo::new(m).unwrap_or_else(|| usage())),
+                    None => usage(),
+                }
+            }
+            _ if days.is_none() => days = Some(arg.parse::<u64>().unwrap_or_else(|_| usage())),
+            _ => path = arg,
+        }
+    }
+    let days = days.unwrap_or_else(|| usage());
+
+    let fish = day06::parse_fish(&app::read_line(&path));
+    match modulus {
+        Some(m) => {
+            let count = day06::count_after_in(&m, &fish, days);
+            println!(
+                "After {} days there are {} fish, modulo {}",
+                days,
+                count,
+                m.modulus()
+            );
+        }
+        None => {
+            let count = day06::count_after_in(&Exact, &fish, days);
+            println!("After {} days there are {} fish", days, count);
+        }
+    }
+}
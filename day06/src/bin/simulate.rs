@@ -0,0 +1,53 @@
+use std::env;
+use std::process;
+
+use day06::{Population, Species};
+
+const USAGE: &str =
+    "Usage: simulate DAYS [--reset N] [--newborn N] [--mortality F] [--name NAME] [FILE]";
+
+// Prints the daily totals of a population as CSV, for charting
+fn main() {
+    let usage = || -> ! {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let lanternfish = Species::lanternfish();
+    let mut mortality = lanternfish.mortality();
+    let mut reset_timer = lanternfish.reset_timer;
+    let mut newborn_timer = lanternfish.newborn_timer;
+    let mut name = lanternfish.name;
+    let mut days = None;
+    let mut path = "input.txt".to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--reset" => reset_timer = value().parse().unwrap_or_else(|_| usage()),
+            "--newborn" => newborn_timer = value().parse().unwrap_or_else(|_| usage()),
+            "--mortality" => mortality = value().parse().unwrap_or_else(|_| usage()),
+            "--name" => name = value(),
+            _ if days.is_none() => days = Some(arg.parse().unwrap_or_else(|_| usage())),
+            _ => path = arg,
+        }
+    }
+    let days = days.unwrap_or_else(|| usage());
+    let species =
+        Species::new(&name, reset_timer, newborn_timer, mortality).unwrap_or_else(|err| {
+            eprintln!("Invalid species: {}", err);
+            process::exit(1);
+        });
+
+    let timers = app::read_line(&path)
+        .split(',')
+        .map(|n| n.trim().parse::<usize>().expect("Invalid input"))
+        .collect::<Vec<_>>();
+    let mut population = Population::new();
+    population
+        .add_species(species, timers)
+        .unwrap_or_else(|err| {
+            eprintln!("Invalid population: {}", err);
+            process::exit(1);
+        });
+    print!("{}", population.run(days).to_csv());
+}
@@ -0,0 +1,253 @@
+pub mod big;
+pub mod population;
+
+use big::BigUint;
+pub use population::{InvalidMortality, InvalidTimer, Population, Species, TimeSeries};
+
+/*
+Each day every fish's timer counts down, and a fish at zero resets to 6 and spawns a newborn at 8.
+That's a linear map on the counts of fish at each timer, so the counts after n days are the
+transition matrix raised to the nth power times the starting counts. Raising the matrix by repeated
+squaring takes O(log n) multiplications of 9x9 matrices.
+
+The arithmetic is supplied by a Ring, so the same matrix code counts with machine integers, exactly
+with arbitrary precision, or modulo some number. Exact counts grow by about 0.037 digits a day, so
+they are only practical up to a few hundred thousand days; counts modulo a number work for any
+number of days.
+*/
+
+pub const NUM_TIMERS: usize = 9;
+pub const RESET_TIMER: usize = 6;
+pub const NEWBORN_TIMER: usize = 8;
+
+pub trait Ring {
+    type Elem: Clone;
+
+    fn zero(&self) -> Self::Elem;
+    fn count(&self, n: usize) -> Self::Elem;
+    fn add(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem;
+    fn mul(&self, a: &Self::Elem, b: &Self::Elem) -> Self::Elem;
+}
+
+// Counts in a usize, panicking if they overflow
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Native;
+
+impl Ring for Native {
+    type Elem = usize;
+
+    fn zero(&self) -> usize {
+        0
+    }
+
+    fn count(&self, n: usize) -> usize {
+        n
+    }
+
+    fn add(&self, a: &usize, b: &usize) -> usize {
+        a.checked_add(*b).expect("Fish count overflows usize")
+    }
+
+    fn mul(&self, a: &usize, b: &usize) -> usize {
+        a.checked_mul(*b).expect("Fish count overflows usize")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Exact;
+
+impl Ring for Exact {
+    type Elem = BigUint;
+
+    fn zero(&self) -> BigUint {
+        BigUint::zero()
+    }
+
+    fn count(&self, n: usize) -> BigUint {
+        BigUint::from(n as u64)
+    }
+
+    fn add(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a.add(b)
+    }
+
+    fn mul(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        a.mul(b)
+    }
+}
+
+// Counts modulo a modulus
+#[derive(Debug, Clone, Copy)]
+pub struct Modulo(u64);
+
+impl Modulo {
+    // None for a modulus of 0
+    pub fn new(m: u64) -> Option<Self> {
+        (m > 0).then_some(Modulo(m))
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Ring for Modulo {
+    type Elem = u64;
+
+    fn zero(&self) -> u64 {
+        0
+    }
+
+    fn count(&self, n: usize) -> u64 {
+        n as u64 % self.0
+    }
+
+    fn add(&self, a: &u64, b: &u64) -> u64 {
+        ((*a as u128 + *b as u128) % self.0 as u128) as u64
+    }
+
+    fn mul(&self, a: &u64, b: &u64) -> u64 {
+        ((*a as u128 * *b as u128) % self.0 as u128) as u64
+    }
+}
+
+// Expected counts, which may be fractional once some of the population dies each day
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Real;
+
+impl Ring for Real {
+    type Elem = f64;
+
+    fn zero(&self) -> f64 {
+        0.0
+    }
+
+    fn count(&self, n: usize) -> f64 {
+        n as f64
+    }
+
+    fn add(&self, a: &f64, b: &f64) -> f64 {
+        a + b
+    }
+
+    fn mul(&self, a: &f64, b: &f64) -> f64 {
+        a * b
+    }
+}
+
+// A square matrix over a ring's elements, in row-major order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<E> {
+    size: usize,
+    cells: Vec<E>,
+}
+
+impl<E: Clone> Matrix<E> {
+    pub fn identity<R: Ring<Elem = E>>(ring: &R, size: usize) -> Self {
+        let mut cells = vec![ring.zero(); size * size];
+        for i in 0..size {
+            cells[i * size + i] = ring.count(1);
+        }
+        Matrix { size, cells }
+    }
+
+    pub fn from_cells(size: usize, cells: Vec<E>) -> Self {
+        assert_eq!(cells.len(), size * size, "Matrix is not square");
+        Matrix { size, cells }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &E {
+        &self.cells[row * self.size + col]
+    }
+
+    pub fn mul<R: Ring<Elem = E>>(&self, ring: &R, other: &Self) -> Self {
+        let n = self.size;
+        let mut cells = Vec::with_capacity(n * n);
+        for row in 0..n {
+            for col in 0..n {
+                let cell = (0..n).fold(ring.zero(), |sum, k| {
+                    ring.add(&sum, &ring.mul(self.get(row, k), other.get(k, col)))
+                });
+                cells.push(cell);
+            }
+        }
+        Matrix { size: n, cells }
+    }
+
+    pub fn map<F: Fn(&E) -> E>(&self, f: F) -> Self {
+        Matrix {
+            size: self.size,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn pow<R: Ring<Elem = E>>(&self, ring: &R, mut exp: u64) -> Self {
+        let mut result = Matrix::identity(ring, self.size);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(ring, &base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(ring, &base);
+            }
+        }
+        result
+    }
+
+    pub fn apply<R: Ring<Elem = E>>(&self, ring: &R, v: &[E]) -> Vec<E> {
+        (0..self.size)
+            .map(|row| {
+                v.iter().enumerate().fold(ring.zero(), |sum, (col, x)| {
+                    ring.add(&sum, &ring.mul(self.get(row, col), x))
+                })
+            })
+            .collect()
+    }
+}
+
+// Row i holds where the fish at timer i come from: each timer is fed by the next one up, and the
+// fish at zero feed both the reset and newborn timers.
+pub fn transition<R: Ring>(ring: &R) -> Matrix<R::Elem> {
+    Species::lanternfish().transition(ring)
+}
+
+pub fn parse_fish(line: &str) -> [usize; NUM_TIMERS] {
+    let inputs = line
+        .split(',')
+        .map(|n| n.parse::<usize>().expect("Invalid input"));
+
+    let mut fish = [0usize; NUM_TIMERS];
+    for input in inputs {
+        fish[input] += 1;
+    }
+    fish
+}
+
+pub fn count_after_in<R: Ring>(ring: &R, fish: &[usize; NUM_TIMERS], days: u64) -> R::Elem {
+    let start: Vec<R::Elem> = fish.iter().map(|&n| ring.count(n)).collect();
+    transition(ring)
+        .pow(ring, days)
+        .apply(ring, &start)
+        .iter()
+        .fold(ring.zero(), |sum, n| ring.add(&sum, n))
+}
+
+pub fn count_after(fish: &[usize; NUM_TIMERS], days: u64) -> usize {
+    count_after_in(&Native, fish, days)
+}
+
+// Fish after 80 days
+pub fn part_a(path: &str) -> String {
+    count_after(&parse_fish(&app::read_line(path)), 80).to_string()
+}
+
+// Fish after 256 days
+pub fn part_b(path: &str) -> String {
+    count_after(&parse_fish(&app::read_line(path)), 256).to_string()
+}
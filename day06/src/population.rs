@@ -0,0 +1,195 @@
+use std::fmt;
+
+use crate::{Matrix, Real, Ring, NEWBORN_TIMER, RESET_TIMER};
+
+/*
+A population of independent species, each with a cyclic lifecycle like the lanternfish: a member's
+timer counts down each day, and when it passes zero the member resets to the reset timer and adds a
+newborn at the newborn timer. A fraction of each species dies every day before the survivors age,
+so counts are expected values and may be fractional.
+*/
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Species {
+    pub name: String,
+    pub reset_timer: usize,
+    pub newborn_timer: usize,
+    // The fraction of the species dying each day, from 0 to 1
+    mortality: f64,
+}
+
+impl Species {
+    pub fn new(
+        name: &str,
+        reset_timer: usize,
+        newborn_timer: usize,
+        mortality: f64,
+    ) -> Result<Self, InvalidMortality> {
+        if !(0.0..=1.0).contains(&mortality) {
+            return Err(InvalidMortality(mortality));
+        }
+        Ok(Species {
+            name: name.to_string(),
+            reset_timer,
+            newborn_timer,
+            mortality,
+        })
+    }
+
+    pub fn lanternfish() -> Self {
+        Species::new("lanternfish", RESET_TIMER, NEWBORN_TIMER, 0.0).unwrap()
+    }
+
+    pub fn mortality(&self) -> f64 {
+        self.mortality
+    }
+
+    pub fn num_timers(&self) -> usize {
+        self.reset_timer.max(self.newborn_timer) + 1
+    }
+
+    // The daily transition on counts at each timer, ignoring mortality
+    pub fn transition<R: Ring>(&self, ring: &R) -> Matrix<R::Elem> {
+        let n = self.num_timers();
+        let one = ring.count(1);
+        let mut cells = vec![ring.zero(); n * n];
+        for timer in 0..n - 1 {
+            cells[timer * n + timer + 1] = one.clone();
+        }
+        cells[self.reset_timer * n] = ring.add(&cells[self.reset_timer * n], &one);
+        cells[self.newborn_timer * n] = ring.add(&cells[self.newborn_timer * n], &one);
+        Matrix::from_cells(n, cells)
+    }
+
+    fn step(&self, counts: &[f64]) -> Vec<f64> {
+        let survival = 1.0 - self.mortality;
+        let mut next: Vec<f64> = counts[1..].iter().map(|n| n * survival).collect();
+        next.push(0.0);
+        let spawning = counts[0] * survival;
+        next[self.reset_timer] += spawning;
+        next[self.newborn_timer] += spawning;
+        next
+    }
+}
+
+// A mortality outside 0 to 1, which would make counts negative or grow without births
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidMortality(pub f64);
+
+impl fmt::Display for InvalidMortality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mortality {} is not between 0 and 1", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTimer {
+    pub species: String,
+    pub timer: usize,
+}
+
+impl fmt::Display for InvalidTimer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "timer {} is out of range for {}",
+            self.timer, self.species
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Population {
+    species: Vec<Species>,
+    // Counts at each timer, per species
+    counts: Vec<Vec<f64>>,
+    day: usize,
+}
+
+impl Population {
+    pub fn new() -> Self {
+        Population::default()
+    }
+
+    // Adds a species, with one member for each of the given timers
+    pub fn add_species(
+        &mut self,
+        species: Species,
+        timers: impl IntoIterator<Item = usize>,
+    ) -> Result<(), InvalidTimer> {
+        let mut counts = vec![0.0; species.num_timers()];
+        for timer in timers {
+            let count = counts.get_mut(timer).ok_or_else(|| InvalidTimer {
+                species: species.name.clone(),
+                timer,
+            })?;
+            *count += 1.0;
+        }
+        self.species.push(species);
+        self.counts.push(counts);
+        Ok(())
+    }
+
+    pub fn species(&self) -> &[Species] {
+        &self.species
+    }
+
+    pub fn day(&self) -> usize {
+        self.day
+    }
+
+    // The total count of each species
+    pub fn totals(&self) -> Vec<f64> {
+        self.counts.iter().map(|c| c.iter().sum()).collect()
+    }
+
+    pub fn step(&mut self) {
+        for (species, counts) in self.species.iter().zip(self.counts.iter_mut()) {
+            *counts = species.step(counts);
+        }
+        self.day += 1;
+    }
+
+    // Steps through the given number of days, recording the totals at the start and after each day
+    pub fn run(&mut self, days: usize) -> TimeSeries {
+        let mut series = TimeSeries {
+            names: self.species.iter().map(|s| s.name.clone()).collect(),
+            start_day: self.day,
+            totals: vec![self.totals()],
+        };
+        for _ in 0..days {
+            self.step();
+            series.totals.push(self.totals());
+        }
+        series
+    }
+
+    // Jumps ahead the given number of days by raising each species' transition to that power
+    pub fn advance(&mut self, days: u64) {
+        for (species, counts) in self.species.iter().zip(self.counts.iter_mut()) {
+            let survival = 1.0 - species.mortality;
+            let transition = species.transition(&Real).map(|n| n * survival);
+            *counts = transition.pow(&Real, days).apply(&Real, counts);
+        }
+        self.day += days as usize;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeries {
+    pub names: Vec<String>,
+    pub start_day: usize,
+    // The totals of each species, per day
+    pub totals: Vec<Vec<f64>>,
+}
+
+impl TimeSeries {
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("day,{}\n", self.names.join(","));
+        for (i, totals) in self.totals.iter().enumerate() {
+            let totals: Vec<String> = totals.iter().map(|n| n.to_string()).collect();
+            csv += &format!("{},{}\n", self.start_day + i, totals.join(","));
+        }
+        csv
+    }
+}
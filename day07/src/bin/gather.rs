@@ -0,0 +1,56 @@
+use std::env;
+use std::process;
+
+use day07::{gather, CostModel, Crabs, Gathering, Linear, Quadratic, Triangular};
+
+const USAGE: &str = "Usage: gather K [--cost linear|triangular|quadratic] [FILE]";
+
+// Splits the crabs between up to K gathering points, listing the crabs (by input index) at each
+fn main() {
+    let usage = || -> ! {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let mut k = None;
+    let mut model = "triangular".to_string();
+    let mut path = "input.txt".to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--cost" => model = args.next().unwrap_or_else(|| usage()),
+            _ if k.is_none() => k = Some(arg.parse::<usize>().unwrap_or_else(|_| usage())),
+            _ => path = arg,
+        }
+    }
+    let k = k.unwrap_or_else(|| usage());
+
+    let crabs = Crabs::new(day07::parse_positions(&app::read_line(&path))).unwrap_or_else(|err| {
+        eprintln!("Invalid crabs: {}", err);
+        process::exit(1);
+    });
+    let gathering = match model.as_str() {
+        "linear" => run(&crabs, &Linear, k),
+        "triangular" => run(&crabs, &Triangular, k),
+        "quadratic" => run(&crabs, &Quadratic, k),
+        _ => usage(),
+    };
+
+    println!("The minimum cost is {}", gathering.cost());
+    for (i, point) in gathering.points.iter().enumerate() {
+        let crabs: Vec<String> = gathering
+            .crabs_at(i)
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        println!(
+            "Position {} costs {} for crabs {}",
+            point.position,
+            point.cost,
+            crabs.join(",")
+        );
+    }
+}
+
+fn run<M: CostModel>(crabs: &Crabs, model: &M, k: usize) -> Gathering {
+    gather(crabs, model, k).expect("No crabs or no gathering points")
+}
@@ -0,0 +1,400 @@
+use std::fmt;
+use std::ops::Range;
+
+/*
+The total cost of moving every crab to a position is a sum of convex functions of the position, so
+it's convex too, and its minimum can be found without trying every position.
+
+For linear costs the minimum is at the median. For quadratic costs it's within half a step of the
+mean, and for triangular costs, d(d + 1) / 2 is half of d^2 + d, which is also minimised within
+half a step of the mean, so only the positions around the mean need checking. With the positions
+sorted and their prefix sums (and sums of squares) to hand, these totals take O(log n) to evaluate
+at any position. Any other convex cost of distance can be minimised by binary searching for where
+the total stops decreasing, evaluating the total directly.
+
+Where several positions share the minimum cost, the lowest is chosen.
+
+Positions are limited to MAX_POSITION, so that squares of distances, and their sums over any number
+of crabs that fit in memory, stay within u128.
+*/
+
+pub const MAX_POSITION: u64 = u32::MAX as u64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionTooLarge(pub u64);
+
+impl fmt::Display for PositionTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "position {} is more than the {} supported",
+            self.0, MAX_POSITION
+        )
+    }
+}
+
+pub fn parse_positions(line: &str) -> Vec<u64> {
+    line.split(',')
+        .map(|n| n.trim().parse::<u64>().expect("Invalid input"))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    pub position: u64,
+    pub cost: u128,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Crabs {
+    // Sorted
+    positions: Vec<u64>,
+    // The index in the input of each sorted position
+    order: Vec<usize>,
+    // prefix[i] is the sum of the first i positions, and prefix_sq[i] the sum of their squares
+    prefix: Vec<u128>,
+    prefix_sq: Vec<u128>,
+}
+
+impl Crabs {
+    pub fn new(positions: Vec<u64>) -> Result<Self, PositionTooLarge> {
+        if let Some(&p) = positions.iter().find(|&&p| p > MAX_POSITION) {
+            return Err(PositionTooLarge(p));
+        }
+        let mut order: Vec<usize> = (0..positions.len()).collect();
+        order.sort_by_key(|&i| positions[i]);
+        let positions: Vec<u64> = order.iter().map(|&i| positions[i]).collect();
+        let mut prefix = Vec::with_capacity(positions.len() + 1);
+        let mut prefix_sq = Vec::with_capacity(positions.len() + 1);
+        let (mut sum, mut sum_sq) = (0u128, 0u128);
+        prefix.push(sum);
+        prefix_sq.push(sum_sq);
+        for &p in positions.iter() {
+            sum += p as u128;
+            sum_sq += p as u128 * p as u128;
+            prefix.push(sum);
+            prefix_sq.push(sum_sq);
+        }
+        Ok(Crabs {
+            positions,
+            order,
+            prefix,
+            prefix_sq,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    // The sorted positions
+    pub fn positions(&self) -> &[u64] {
+        &self.positions
+    }
+
+    // The input index of the crab at the given sorted index
+    pub fn input_index(&self, sorted: usize) -> usize {
+        self.order[sorted]
+    }
+
+    // A range of crabs, by sorted index
+    pub fn group(&self, range: Range<usize>) -> Group<'_> {
+        Group {
+            positions: &self.positions[range.clone()],
+            prefix: &self.prefix[range.start..=range.end],
+            prefix_sq: &self.prefix_sq[range.start..=range.end],
+        }
+    }
+
+    pub fn all(&self) -> Group<'_> {
+        self.group(0..self.positions.len())
+    }
+
+    pub fn align<M: CostModel>(&self, model: &M) -> Option<Alignment> {
+        model.align(&self.all())
+    }
+}
+
+// A range of crabs from a sorted Crabs, with their prefix sums
+#[derive(Debug, Clone, Copy)]
+pub struct Group<'a> {
+    positions: &'a [u64],
+    // One longer than positions, starting from the sum before the group
+    prefix: &'a [u128],
+    prefix_sq: &'a [u128],
+}
+
+impl Group<'_> {
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    // The sorted positions
+    pub fn positions(&self) -> &[u64] {
+        self.positions
+    }
+
+    pub fn range(&self) -> Option<(u64, u64)> {
+        Some((*self.positions.first()?, *self.positions.last()?))
+    }
+
+    pub fn sum(&self) -> u128 {
+        self.prefix[self.positions.len()] - self.prefix[0]
+    }
+
+    // The total distance to x of the crabs left of x, and of the rest
+    pub fn split(&self, x: u64) -> (u128, u128) {
+        let k = self.positions.partition_point(|&p| p < x);
+        let n = self.positions.len();
+        let x = x as u128;
+        let left = k as u128 * x - (self.prefix[k] - self.prefix[0]);
+        let right = (self.prefix[n] - self.prefix[k]) - (n - k) as u128 * x;
+        (left, right)
+    }
+
+    // The sum of squared distances to x
+    pub fn squares(&self, x: u64) -> u128 {
+        let n = self.positions.len() as u128;
+        let sum_sq = self.prefix_sq[self.positions.len()] - self.prefix_sq[0];
+        let x = x as u128;
+        // sum p^2 - 2x sum p + n x^2, arranged to stay non-negative
+        sum_sq + n * x * x - 2 * x * self.sum()
+    }
+
+    // The position with the least cost among a few either side of the mean
+    fn align_near_mean<F>(&self, total_cost: F) -> Option<Alignment>
+    where
+        F: Fn(u64) -> u128,
+    {
+        let (min, max) = self.range()?;
+        let mean = (self.sum() / self.positions.len() as u128) as u64;
+        (mean.saturating_sub(1)..=mean.saturating_add(2))
+            .map(|x| x.clamp(min, max))
+            .map(|position| Alignment {
+                position,
+                cost: total_cost(position),
+            })
+            .min_by_key(|a| (a.cost, a.position))
+    }
+
+    // Minimises any total cost that's convex in the position, evaluating it O(log range) times.
+    pub fn align_convex<F>(&self, total_cost: F) -> Option<Alignment>
+    where
+        F: Fn(u64) -> u128,
+    {
+        let (mut lo, mut hi) = self.range()?;
+        // Find the first position where the cost stops decreasing
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if total_cost(mid + 1) >= total_cost(mid) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        Some(Alignment {
+            position: lo,
+            cost: total_cost(lo),
+        })
+    }
+}
+
+// The fuel cost of moving a crab some distance, which must be convex and non-decreasing in the
+// distance. The totals and alignment can be overridden where there's a faster way.
+pub trait CostModel {
+    fn cost(&self, distance: u64) -> u128;
+
+    fn total(&self, group: &Group, x: u64) -> u128 {
+        group
+            .positions()
+            .iter()
+            .map(|&p| self.cost(p.abs_diff(x)))
+            .sum()
+    }
+
+    fn align(&self, group: &Group) -> Option<Alignment> {
+        group.align_convex(|x| self.total(group, x))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Linear;
+
+impl CostModel for Linear {
+    fn cost(&self, distance: u64) -> u128 {
+        distance as u128
+    }
+
+    fn total(&self, group: &Group, x: u64) -> u128 {
+        let (left, right) = group.split(x);
+        left + right
+    }
+
+    fn align(&self, group: &Group) -> Option<Alignment> {
+        // With an even number of crabs, every position between the middle two costs the same
+        let position = *group.positions().get(group.len().checked_sub(1)? / 2)?;
+        Some(Alignment {
+            position,
+            cost: self.total(group, position),
+        })
+    }
+}
+
+// Each step costs one more than the last
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Triangular;
+
+impl CostModel for Triangular {
+    fn cost(&self, distance: u64) -> u128 {
+        let d = distance as u128;
+        d * (d + 1) / 2
+    }
+
+    fn total(&self, group: &Group, x: u64) -> u128 {
+        let (left, right) = group.split(x);
+        (group.squares(x) + left + right) / 2
+    }
+
+    fn align(&self, group: &Group) -> Option<Alignment> {
+        group.align_near_mean(|x| self.total(group, x))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Quadratic;
+
+impl CostModel for Quadratic {
+    fn cost(&self, distance: u64) -> u128 {
+        distance as u128 * distance as u128
+    }
+
+    fn total(&self, group: &Group, x: u64) -> u128 {
+        group.squares(x)
+    }
+
+    fn align(&self, group: &Group) -> Option<Alignment> {
+        group.align_near_mean(|x| self.total(group, x))
+    }
+}
+
+// Any convex, non-decreasing cost of distance
+#[derive(Debug, Clone, Copy)]
+pub struct Custom<F>(pub F);
+
+impl<F: Fn(u64) -> u128> CostModel for Custom<F> {
+    fn cost(&self, distance: u64) -> u128 {
+        (self.0)(distance)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gathering {
+    pub points: Vec<Alignment>,
+    // The index into points of where each crab goes, in input order
+    pub assignment: Vec<usize>,
+}
+
+impl Gathering {
+    pub fn cost(&self) -> u128 {
+        self.points.iter().map(|p| p.cost).sum()
+    }
+
+    // The input indexes of the crabs going to a point
+    pub fn crabs_at(&self, point: usize) -> Vec<usize> {
+        (0..self.assignment.len())
+            .filter(|&i| self.assignment[i] == point)
+            .collect()
+    }
+}
+
+// Chooses up to k gathering points to minimise the total cost. With a convex cost, each point
+// gathers a contiguous run of the sorted crabs, so the best split into runs is found by dynamic
+// programming over the cost of each possible run. That takes O(n^2) run alignments and memory,
+// plus O(k n^2) time, so suits thousands of crabs rather than millions.
+pub fn gather<M: CostModel>(crabs: &Crabs, model: &M, k: usize) -> Option<Gathering> {
+    let n = crabs.len();
+    let k = k.min(n);
+    if k == 0 {
+        return None;
+    }
+
+    // run_cost[i][j - i - 1] is the cost of gathering crabs i..j at one point
+    let run_cost: Vec<Vec<u128>> = (0..n)
+        .map(|i| {
+            (i + 1..=n)
+                .map(|j| model.align(&crabs.group(i..j)).map_or(0, |a| a.cost))
+                .collect()
+        })
+        .collect();
+    let run = |i: usize, j: usize| run_cost[i][j - i - 1];
+
+    // best[j] is the least cost of gathering the first j crabs at the points so far, and
+    // starts[c][j] is where the last of c + 1 runs starts in that solution
+    let mut best: Vec<u128> = (0..=n)
+        .map(|j| if j == 0 { 0 } else { run(0, j) })
+        .collect();
+    let mut starts = vec![vec![0; n + 1]];
+    for _ in 1..k {
+        let mut next = vec![0; n + 1];
+        let mut start = vec![0; n + 1];
+        for j in 1..=n {
+            // An empty last run leaves fewer points as an option
+            next[j] = best[j];
+            start[j] = j;
+            for (i, &before) in best.iter().enumerate().take(j) {
+                let cost = before + run(i, j);
+                if cost < next[j] {
+                    next[j] = cost;
+                    start[j] = i;
+                }
+            }
+        }
+        best = next;
+        starts.push(start);
+    }
+
+    let mut runs = Vec::with_capacity(k);
+    let mut end = n;
+    for start in starts.iter().rev() {
+        let begin = start[end];
+        if begin < end {
+            runs.push(begin..end);
+        }
+        end = begin;
+    }
+    runs.reverse();
+
+    let mut points = Vec::with_capacity(runs.len());
+    let mut assignment = vec![0; n];
+    for (point, run) in runs.into_iter().enumerate() {
+        points.push(model.align(&crabs.group(run.clone()))?);
+        for sorted in run {
+            assignment[crabs.input_index(sorted)] = point;
+        }
+    }
+    Some(Gathering { points, assignment })
+}
+
+fn align_cost<M: CostModel>(path: &str, model: &M) -> String {
+    let crabs = Crabs::new(parse_positions(&app::read_line(path)))
+        .unwrap_or_else(|err| panic!("Invalid crabs: {}", err));
+    crabs.align(model).expect("Input is empty").cost.to_string()
+}
+
+// The least fuel to align when each step costs one
+pub fn part_a(path: &str) -> String {
+    align_cost(path, &Linear)
+}
+
+// The least fuel to align when each step costs one more than the last
+pub fn part_b(path: &str) -> String {
+    align_cost(path, &Triangular)
+}
@@ -0,0 +1,49 @@
+use std::env;
+use std::process;
+
+use day08::Glyphs;
+
+const USAGE: &str = "Usage: wiring [--glyphs digits|hex|14|16] [FILE]";
+
+// Prints the wiring and reading of each panel, or why they can't be decoded
+fn main() {
+    let usage = || -> ! {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let mut glyphs = Glyphs::digits();
+    let mut path = "input.txt".to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--glyphs" => {
+                glyphs = match args.next().as_deref() {
+                    Some("digits") => Glyphs::digits(),
+                    Some("hex") => Glyphs::hex(),
+                    Some("14") => Glyphs::fourteen_segment(),
+                    Some("16") => Glyphs::sixteen_segment(),
+                    _ => usage(),
+                }
+            }
+            _ => path = arg,
+        }
+    }
+
+    let panels = day08::parse_panels(app::read_lines(&path)).unwrap_or_else(|errors| {
+        for err in errors {
+            eprintln!("Invalid panel: {}", err);
+        }
+        process::exit(1);
+    });
+
+    for (line, panel) in panels.iter() {
+        let wiring = match panel.wiring(&glyphs) {
+            Ok(wiring) => wiring.to_string(),
+            Err(err) => format!("unknown ({})", err),
+        };
+        match panel.read(&glyphs) {
+            Ok(shown) => println!("line {}: wiring {}, showing {}", line, wiring, shown),
+            Err(err) => println!("line {}: wiring {}, undecodable: {}", line, wiring, err),
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use std::fmt;
+
+use crate::{Glyphs, SegmentSet};
+
+/*
+Solves for the wiring, the segment each wire is connected to, from whatever patterns have been
+observed on a display showing glyphs from a table. Each wire keeps a bitmask of the segments it
+could still be connected to, and each pattern can only be a glyph with the same number of segments
+whose shape fits those candidates. The wires in a pattern must go to that glyph's segments and the
+rest must go elsewhere, which narrows the candidates, which in turn rules out more glyphs. Once
+that stops making progress, the solver guesses a segment for the least certain wire and carries on.
+
+Displays with many segments can have far too many wirings to list when few patterns have been
+seen, so rather than listing them, questions like "can this wire go to that segment?" or "can
+this pattern be that glyph?" are answered by searching for a single wiring with the extra
+constraint.
+*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    // The pattern lights a wire the display doesn't have
+    NoSuchWire(SegmentSet),
+    // The pattern lights a number of segments that no glyph does
+    NoSuchGlyph(SegmentSet),
+    // No wiring shows the pattern as a glyph along with the patterns before it
+    Contradiction(SegmentSet),
+    // Several wirings fit the patterns, and they disagree on where these wires go
+    AmbiguousWiring(SegmentSet),
+    // The wirings that fit disagree on which glyph the pattern shows
+    AmbiguousGlyph {
+        pattern: SegmentSet,
+        symbols: Vec<char>,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::NoSuchWire(pattern) => {
+                write!(f, "{} lights a wire the display doesn't have", pattern)
+            }
+            DecodeError::NoSuchGlyph(pattern) => write!(
+                f,
+                "no glyph lights {} segments, as {} does",
+                pattern.count(),
+                pattern
+            ),
+            DecodeError::Contradiction(pattern) => write!(
+                f,
+                "{} can't be shown along with the patterns before it",
+                pattern
+            ),
+            DecodeError::AmbiguousWiring(wires) => {
+                write!(f, "several wirings fit, which disagree on wires {}", wires)
+            }
+            DecodeError::AmbiguousGlyph { pattern, symbols } => {
+                let symbols: Vec<String> = symbols.iter().map(|s| s.to_string()).collect();
+                write!(f, "{} could be any of {}", pattern, symbols.join(", "))
+            }
+        }
+    }
+}
+
+// The segment each wire is connected to, by index
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Wiring {
+    segments: Vec<u8>,
+}
+
+impl Wiring {
+    pub fn num_wires(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn segment(&self, wire: usize) -> usize {
+        self.segments[wire] as usize
+    }
+
+    // The segments lit by a pattern of wires
+    pub fn translate(&self, pattern: SegmentSet) -> SegmentSet {
+        let value = (0..self.segments.len())
+            .filter(|&wire| pattern.contains(wire))
+            .fold(0, |value, wire| value | 1 << self.segments[wire]);
+        SegmentSet::from_bits(value)
+    }
+
+    // The index of the glyph a pattern shows
+    pub fn glyph(&self, glyphs: &Glyphs, pattern: SegmentSet) -> Option<usize> {
+        glyphs.find(self.translate(pattern).bits())
+    }
+
+    // The wires lit to show a glyph
+    pub fn pattern(&self, glyphs: &Glyphs, glyph: usize) -> SegmentSet {
+        let shape = glyphs.shape(glyph);
+        let value = (0..self.segments.len())
+            .filter(|&wire| shape & 1 << self.segments[wire] != 0)
+            .fold(0, |value, wire| value | 1 << wire);
+        SegmentSet::from_bits(value)
+    }
+}
+
+// The segment letter for each wire in turn, so "cfgabde" connects wire a to segment c
+impl fmt::Display for Wiring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &segment in self.segments.iter() {
+            write!(f, "{}", (b'a' + segment) as char)?;
+        }
+        Ok(())
+    }
+}
+
+// A pattern of wires, and the shapes it could be showing
+#[derive(Debug, Clone)]
+struct Constraint {
+    pattern: u32,
+    shapes: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+struct Solver<'a> {
+    glyphs: &'a Glyphs,
+    constraints: Vec<Constraint>,
+}
+
+impl<'a> Solver<'a> {
+    // Checks every pattern can be some glyph before solving for any of them
+    fn new(glyphs: &'a Glyphs, patterns: &[SegmentSet]) -> Result<Self, DecodeError> {
+        let mut solver = Solver {
+            glyphs,
+            constraints: Vec::with_capacity(patterns.len()),
+        };
+        for &pattern in patterns {
+            if pattern.bits() & !glyphs.all_segments() != 0 {
+                return Err(DecodeError::NoSuchWire(pattern));
+            }
+            let shapes: Vec<u32> = glyphs
+                .shapes()
+                .iter()
+                .filter(|s| s.count_ones() as usize == pattern.count())
+                .copied()
+                .collect();
+            if shapes.is_empty() {
+                return Err(DecodeError::NoSuchGlyph(pattern));
+            }
+            solver.push(pattern, shapes);
+        }
+        Ok(solver)
+    }
+
+    fn push(&mut self, pattern: SegmentSet, shapes: Vec<u32>) {
+        self.constraints.push(Constraint {
+            pattern: pattern.bits(),
+            shapes,
+        });
+    }
+
+    fn start(&self) -> Vec<u32> {
+        vec![self.glyphs.all_segments(); self.glyphs.num_segments()]
+    }
+
+    // Narrows the candidates until nothing changes, returning false if some wire runs out
+    fn propagate(&self, cands: &mut [u32]) -> bool {
+        let all = self.glyphs.all_segments();
+        let mut allowed = vec![0u32; cands.len()];
+        loop {
+            let before = cands.to_vec();
+            for constraint in self.constraints.iter() {
+                // The wires in the pattern go to the glyph's segments and the rest to the others
+                let side = |shape: u32, wire: usize| {
+                    if constraint.pattern & 1 << wire != 0 {
+                        shape
+                    } else {
+                        !shape & all
+                    }
+                };
+                allowed.fill(0);
+                let mut fits_any = false;
+                for &shape in constraint.shapes.iter() {
+                    if (0..cands.len()).all(|wire| cands[wire] & side(shape, wire) != 0) {
+                        fits_any = true;
+                        for (wire, allow) in allowed.iter_mut().enumerate() {
+                            *allow |= side(shape, wire);
+                        }
+                    }
+                }
+                if !fits_any {
+                    return false;
+                }
+                for (cand, allow) in cands.iter_mut().zip(allowed.iter()) {
+                    *cand &= allow;
+                }
+            }
+
+            // A wire left with one segment has it to itself
+            for wire in 0..cands.len() {
+                let cand = cands[wire];
+                if cand.count_ones() == 1 {
+                    for (other, c) in cands.iter_mut().enumerate() {
+                        if other != wire {
+                            *c &= !cand;
+                        }
+                    }
+                }
+            }
+            // A segment only one wire can go to must be that wire's
+            for segment in 0..cands.len() {
+                let bit = 1 << segment;
+                let mut wires = (0..cands.len()).filter(|&wire| cands[wire] & bit != 0);
+                match (wires.next(), wires.next()) {
+                    (None, _) => return false,
+                    (Some(wire), None) => cands[wire] = bit,
+                    _ => {}
+                }
+            }
+
+            if cands.contains(&0) {
+                return false;
+            }
+            if *cands == before[..] {
+                return true;
+            }
+        }
+    }
+
+    // Finds up to limit wirings that fit the constraints, starting from the given candidates
+    fn search(&self, mut cands: Vec<u32>, limit: usize, found: &mut Vec<Wiring>) {
+        if found.len() >= limit || !self.propagate(&mut cands) {
+            return;
+        }
+        let guess = (0..cands.len())
+            .filter(|&wire| cands[wire].count_ones() > 1)
+            .min_by_key(|&wire| cands[wire].count_ones());
+        match guess {
+            None => found.push(Wiring {
+                segments: cands.iter().map(|c| c.trailing_zeros() as u8).collect(),
+            }),
+            Some(wire) => {
+                for segment in 0..cands.len() {
+                    if cands[wire] & 1 << segment != 0 {
+                        let mut next = cands.clone();
+                        next[wire] = 1 << segment;
+                        self.search(next, limit, found);
+                    }
+                }
+            }
+        }
+    }
+
+    fn first(&self, cands: Vec<u32>) -> Option<Wiring> {
+        let mut found = Vec::with_capacity(1);
+        self.search(cands, 1, &mut found);
+        found.pop()
+    }
+
+    // Finds the first pattern that doesn't fit with the ones before it
+    fn contradiction(&self) -> DecodeError {
+        let mut solver = Solver {
+            glyphs: self.glyphs,
+            constraints: Vec::with_capacity(self.constraints.len()),
+        };
+        for constraint in self.constraints.iter() {
+            solver.constraints.push(constraint.clone());
+            if solver.first(solver.start()).is_none() {
+                return DecodeError::Contradiction(SegmentSet::from_bits(constraint.pattern));
+            }
+        }
+        unreachable!("Every pattern fits but no wiring does");
+    }
+
+    // The glyphs a pattern can show in some wiring
+    fn possible_glyphs(&self, pattern: SegmentSet) -> Vec<usize> {
+        (0..self.glyphs.len())
+            .filter(|&glyph| {
+                let shape = self.glyphs.shape(glyph);
+                if shape.count_ones() as usize != pattern.count() {
+                    return false;
+                }
+                let mut solver = self.clone();
+                solver.push(pattern, vec![shape]);
+                solver.first(solver.start()).is_some()
+            })
+            .collect()
+    }
+}
+
+// The one wiring that shows each of the patterns as a glyph
+pub fn solve(glyphs: &Glyphs, patterns: &[SegmentSet]) -> Result<Wiring, DecodeError> {
+    let solver = Solver::new(glyphs, patterns)?;
+    let mut found = Vec::with_capacity(2);
+    solver.search(solver.start(), 2, &mut found);
+    match found.len() {
+        0 => Err(solver.contradiction()),
+        1 => Ok(found.swap_remove(0)),
+        _ => {
+            // A wire is pinned down if no other segment works for it
+            let wiring = &found[0];
+            let wires = (0..wiring.num_wires())
+                .filter(|&wire| {
+                    (0..wiring.num_wires()).any(|segment| {
+                        let mut cands = solver.start();
+                        cands[wire] = 1 << segment;
+                        segment != wiring.segment(wire) && solver.first(cands).is_some()
+                    })
+                })
+                .fold(0, |value, wire| value | 1 << wire);
+            Err(DecodeError::AmbiguousWiring(SegmentSet::from_bits(wires)))
+        }
+    }
+}
+
+// The glyphs shown by the displays, which must be the same for every wiring that fits both the
+// patterns and the displays. This may succeed even if the wiring itself can't be pinned down.
+pub fn decode(
+    glyphs: &Glyphs,
+    patterns: &[SegmentSet],
+    displays: &[SegmentSet],
+) -> Result<Vec<usize>, DecodeError> {
+    let observed: Vec<SegmentSet> = patterns.iter().chain(displays).copied().collect();
+    let solver = Solver::new(glyphs, &observed)?;
+    if solver.first(solver.start()).is_none() {
+        return Err(solver.contradiction());
+    }
+    displays
+        .iter()
+        .map(|&display| match solver.possible_glyphs(display)[..] {
+            [glyph] => Ok(glyph),
+            ref possible => Err(DecodeError::AmbiguousGlyph {
+                pattern: display,
+                symbols: possible.iter().map(|&g| glyphs.symbol(g)).collect(),
+            }),
+        })
+        .collect()
+}
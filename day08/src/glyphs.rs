@@ -0,0 +1,256 @@
+use std::fmt;
+
+use crate::MAX_SEGMENTS;
+
+/*
+The shapes a display can show, each a set of lit segments. Seven-segment displays use the layout
+from the puzzle, with segments lettered top to bottom and left to right:
+
+     aaaa
+    b    c
+    b    c
+     dddd
+    e    f
+    e    f
+     gggg
+
+The alphanumeric displays add a split middle bar, two diagonals and a centre bar in each half, and
+the sixteen-segment display also splits the top and bottom bars, with o and p as the right halves:
+
+     aaaaoooo
+    bi  j  kc
+    b i j k c
+     dddeeee
+    f l m n g
+    fl  m  ng
+     hhhhpppp
+
+On the fourteen-segment display, a and h span the whole width.
+*/
+
+// Seven segments
+const A: u32 = 1 << 0;
+const B: u32 = 1 << 1;
+const C: u32 = 1 << 2;
+const D: u32 = 1 << 3;
+const E: u32 = 1 << 4;
+const F: u32 = 1 << 5;
+const G: u32 = 1 << 6;
+
+// Fourteen segments: the top, upper left and right, middle left and right, lower left and right,
+// and bottom, then the diagonals and centre bars, upper left to lower right
+const TOP: u32 = 1 << 0;
+const UL: u32 = 1 << 1;
+const UR: u32 = 1 << 2;
+const ML: u32 = 1 << 3;
+const MR: u32 = 1 << 4;
+const LL: u32 = 1 << 5;
+const LR: u32 = 1 << 6;
+const BOT: u32 = 1 << 7;
+const DUL: u32 = 1 << 8;
+const UC: u32 = 1 << 9;
+const DUR: u32 = 1 << 10;
+const DLL: u32 = 1 << 11;
+const LC: u32 = 1 << 12;
+const DLR: u32 = 1 << 13;
+const MID: u32 = ML | MR;
+
+// The right halves of the top and bottom bars on a sixteen-segment display
+const TOP2: u32 = 1 << 14;
+const BOT2: u32 = 1 << 15;
+
+const DIGITS: [(char, u32); 10] = [
+    ('0', A | B | C | E | F | G),
+    ('1', C | F),
+    ('2', A | C | D | E | G),
+    ('3', A | C | D | F | G),
+    ('4', B | C | D | F),
+    ('5', A | B | D | F | G),
+    ('6', A | B | D | E | F | G),
+    ('7', A | C | F),
+    ('8', A | B | C | D | E | F | G),
+    ('9', A | B | C | D | F | G),
+];
+
+// Lower case b and d keep them apart from 8 and 0
+const HEX_LETTERS: [(char, u32); 6] = [
+    ('A', A | B | C | D | E | F),
+    ('b', B | D | E | F | G),
+    ('C', A | B | E | G),
+    ('d', C | D | E | F | G),
+    ('E', A | B | D | E | G),
+    ('F', A | B | D | E),
+];
+
+const ALPHANUMERIC: [(char, u32); 36] = [
+    ('0', TOP | UL | UR | LL | LR | BOT | DUR | DLL),
+    ('1', UR | LR),
+    ('2', TOP | UR | MID | LL | BOT),
+    ('3', TOP | UR | MR | LR | BOT),
+    ('4', UL | UR | MID | LR),
+    ('5', TOP | UL | ML | DLR | BOT),
+    ('6', TOP | UL | MID | LL | LR | BOT),
+    ('7', TOP | UR | LR),
+    ('8', TOP | UL | UR | MID | LL | LR | BOT),
+    ('9', TOP | UL | UR | MID | LR | BOT),
+    ('A', TOP | UL | UR | MID | LL | LR),
+    ('B', TOP | UR | MR | LR | BOT | UC | LC),
+    ('C', TOP | UL | LL | BOT),
+    ('D', TOP | UR | LR | BOT | UC | LC),
+    ('E', TOP | UL | MID | LL | BOT),
+    ('F', TOP | UL | ML | LL),
+    ('G', TOP | UL | MR | LL | LR | BOT),
+    ('H', UL | UR | MID | LL | LR),
+    ('I', TOP | UC | LC | BOT),
+    ('J', UR | LR | BOT | LL),
+    ('K', UL | ML | LL | DUR | DLR),
+    ('L', UL | LL | BOT),
+    ('M', UL | UR | LL | LR | DUL | DUR),
+    ('N', UL | UR | LL | LR | DUL | DLR),
+    ('O', TOP | UL | UR | LL | LR | BOT),
+    ('P', TOP | UL | UR | MID | LL),
+    ('Q', TOP | UL | UR | LL | LR | BOT | DLR),
+    ('R', TOP | UL | UR | MID | LL | DLR),
+    ('S', TOP | UL | MID | LR | BOT),
+    ('T', TOP | UC | LC),
+    ('U', UL | UR | LL | LR | BOT),
+    ('V', UL | LL | DLL | DUR),
+    ('W', UL | UR | LL | LR | DLL | DLR),
+    ('X', DUL | DUR | DLL | DLR),
+    ('Y', DUL | DUR | LC),
+    ('Z', TOP | DUR | DLL | BOT),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GlyphError {
+    TooManySegments(usize),
+    // The glyph lights a segment the display doesn't have
+    SegmentOutOfRange(char),
+    // Two glyphs light the same segments, so they can't be told apart
+    SameShape(char, char),
+}
+
+impl fmt::Display for GlyphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlyphError::TooManySegments(n) => write!(
+                f,
+                "{} segments is more than the {} supported",
+                n, MAX_SEGMENTS
+            ),
+            GlyphError::SegmentOutOfRange(c) => {
+                write!(f, "{} lights a segment the display doesn't have", c)
+            }
+            GlyphError::SameShape(a, b) => write!(f, "{} and {} have the same shape", a, b),
+        }
+    }
+}
+
+// The symbols a display can show, with segment i of each shape as bit i
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyphs {
+    num_segments: usize,
+    symbols: Vec<char>,
+    shapes: Vec<u32>,
+}
+
+impl Glyphs {
+    pub fn new(num_segments: usize, glyphs: &[(char, u32)]) -> Result<Self, GlyphError> {
+        if num_segments > MAX_SEGMENTS {
+            return Err(GlyphError::TooManySegments(num_segments));
+        }
+        let all = Glyphs::mask(num_segments);
+        let mut table = Glyphs {
+            num_segments,
+            symbols: Vec::with_capacity(glyphs.len()),
+            shapes: Vec::with_capacity(glyphs.len()),
+        };
+        for &(symbol, shape) in glyphs {
+            if shape & !all != 0 {
+                return Err(GlyphError::SegmentOutOfRange(symbol));
+            }
+            if let Some(other) = table.find(shape) {
+                return Err(GlyphError::SameShape(table.symbols[other], symbol));
+            }
+            table.symbols.push(symbol);
+            table.shapes.push(shape);
+        }
+        Ok(table)
+    }
+
+    fn mask(num_segments: usize) -> u32 {
+        (1 << num_segments) - 1
+    }
+
+    // The digits 0 to 9 on a seven-segment display, which is what the puzzle uses
+    pub fn digits() -> Self {
+        Glyphs::new(7, &DIGITS).unwrap()
+    }
+
+    // The digits 0 to 9 and A to F on a seven-segment display
+    pub fn hex() -> Self {
+        let glyphs: Vec<(char, u32)> = DIGITS.iter().chain(HEX_LETTERS.iter()).copied().collect();
+        Glyphs::new(7, &glyphs).unwrap()
+    }
+
+    // The digits 0 to 9 and letters A to Z on a fourteen-segment display
+    pub fn fourteen_segment() -> Self {
+        Glyphs::new(14, &ALPHANUMERIC).unwrap()
+    }
+
+    // The digits 0 to 9, letters A to Z and square brackets on a sixteen-segment display
+    pub fn sixteen_segment() -> Self {
+        let split = |shape: u32| {
+            let mut shape = shape;
+            if shape & TOP != 0 {
+                shape |= TOP2;
+            }
+            if shape & BOT != 0 {
+                shape |= BOT2;
+            }
+            shape
+        };
+        let mut glyphs: Vec<(char, u32)> = ALPHANUMERIC
+            .iter()
+            .map(|&(symbol, shape)| (symbol, split(shape)))
+            .collect();
+        // Brackets light one half of each bar, which tells the halves apart
+        glyphs.push(('[', TOP | UL | LL | BOT));
+        glyphs.push((']', TOP2 | UR | LR | BOT2));
+        Glyphs::new(16, &glyphs).unwrap()
+    }
+
+    pub fn num_segments(&self) -> usize {
+        self.num_segments
+    }
+
+    // A mask of every segment on the display
+    pub fn all_segments(&self) -> u32 {
+        Glyphs::mask(self.num_segments)
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    pub fn symbol(&self, glyph: usize) -> char {
+        self.symbols[glyph]
+    }
+
+    pub fn shape(&self, glyph: usize) -> u32 {
+        self.shapes[glyph]
+    }
+
+    pub fn shapes(&self) -> &[u32] {
+        &self.shapes
+    }
+
+    // The glyph with exactly the given segments lit
+    pub fn find(&self, shape: u32) -> Option<usize> {
+        self.shapes.iter().position(|&s| s == shape)
+    }
+}
@@ -0,0 +1,151 @@
+pub mod decode;
+pub mod glyphs;
+pub mod parse;
+
+use std::fmt;
+use std::str::FromStr;
+
+pub use decode::{DecodeError, Wiring};
+pub use glyphs::{GlyphError, Glyphs};
+pub use parse::{parse_panels, ParseLineError, ParsePanelError};
+
+// Wires are lettered from a to z
+pub const MAX_SEGMENTS: usize = 26;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSegmentError {
+    InvalidChar(char),
+    RepeatedWire(char),
+}
+
+impl fmt::Display for ParseSegmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSegmentError::InvalidChar(c) => write!(f, "'{}' is not a wire", c),
+            ParseSegmentError::RepeatedWire(c) => write!(f, "wire {} appears twice", c),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SegmentSet {
+    value: u32,
+}
+
+pub struct Panel {
+    inputs: Vec<SegmentSet>,
+    displays: Vec<SegmentSet>,
+}
+
+impl SegmentSet {
+    pub fn from_bits(value: u32) -> Self {
+        SegmentSet { value }
+    }
+
+    // Bit i is set if wire i is lit
+    pub fn bits(&self) -> u32 {
+        self.value
+    }
+
+    pub fn contains(&self, wire: usize) -> bool {
+        self.value & 1 << wire != 0
+    }
+
+    pub fn count(&self) -> usize {
+        self.value.count_ones() as usize
+    }
+
+    pub fn intersect(&self, rhs: &Self) -> Self {
+        SegmentSet {
+            value: self.value & rhs.value,
+        }
+    }
+}
+
+impl fmt::Display for SegmentSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for wire in 0..MAX_SEGMENTS {
+            if self.contains(wire) {
+                write!(f, "{}", (b'a' + wire as u8) as char)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SegmentSet {
+    type Err = ParseSegmentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut value = 0u32;
+        for c in s.chars() {
+            match c {
+                'a'..='z' => {
+                    let bit = 1 << (c as u32 - 'a' as u32);
+                    if value & bit != 0 {
+                        return Err(Self::Err::RepeatedWire(c));
+                    }
+                    value |= bit;
+                }
+                _ => return Err(Self::Err::InvalidChar(c)),
+            }
+        }
+        Ok(SegmentSet { value })
+    }
+}
+
+impl Panel {
+    pub fn displays(&self) -> &[SegmentSet] {
+        &self.displays
+    }
+
+    pub fn inputs(&self) -> &[SegmentSet] {
+        &self.inputs
+    }
+
+    // Solves for the wiring from the inputs alone
+    pub fn wiring(&self, glyphs: &Glyphs) -> Result<Wiring, DecodeError> {
+        decode::solve(glyphs, &self.inputs)
+    }
+
+    // The symbols on the displays. The inputs need not cover every glyph, as long as the
+    // displays can be told apart.
+    pub fn read(&self, glyphs: &Glyphs) -> Result<String, DecodeError> {
+        let shown = decode::decode(glyphs, &self.inputs, &self.displays)?;
+        Ok(shown.iter().map(|&glyph| glyphs.symbol(glyph)).collect())
+    }
+
+    // The number on the displays, read as decimal digits
+    pub fn solve_value(&self) -> Result<usize, DecodeError> {
+        let digits = decode::decode(&Glyphs::digits(), &self.inputs, &self.displays)?;
+        Ok(digits.iter().fold(0, |sum, digit| sum * 10 + digit))
+    }
+}
+
+fn read_panels(path: &str) -> Vec<(usize, Panel)> {
+    parse_panels(app::read_lines(path)).unwrap_or_else(|errors| {
+        let errors: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        panic!("Invalid panels:\n{}", errors.join("\n"))
+    })
+}
+
+// Displays showing 1, 4, 7 or 8, which have a unique number of segments
+pub fn part_a(path: &str) -> String {
+    read_panels(path)
+        .iter()
+        .flat_map(|(_, p)| p.displays().iter())
+        .filter(|segment| matches!(segment.count(), 2 | 3 | 4 | 7))
+        .count()
+        .to_string()
+}
+
+// The sum of the decoded displays
+pub fn part_b(path: &str) -> String {
+    let mut sum = 0;
+    for (line, panel) in read_panels(path) {
+        sum += panel
+            .solve_value()
+            .unwrap_or_else(|err| panic!("Unable to decode line {}: {}", line, err));
+    }
+    sum.to_string()
+}
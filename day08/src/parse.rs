@@ -0,0 +1,204 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Panel, ParseSegmentError, SegmentSet};
+
+/*
+A panel is a list of patterns, a '|', and a list of displays, separated by any whitespace. The
+separator doesn't need spaces around it. Each token keeps which side of the separator it was on,
+its position on that side and the columns it spans, counting characters rather than bytes, so
+errors can point at it.
+
+The patterns are meant to be distinct, so a pattern seen twice, even with its wires in a different
+order, is an error. Displays often repeat, so they're not checked.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Patterns,
+    Displays,
+}
+
+// A range of columns in a line, counting from 1, with end included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.start == self.end {
+            write!(f, "column {}", self.start)
+        } else {
+            write!(f, "columns {}-{}", self.start, self.end)
+        }
+    }
+}
+
+// Where a pattern or display is in its line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub side: Side,
+    // Counting from 1 on its side of the separator
+    pub index: usize,
+    pub span: Span,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.side {
+            Side::Patterns => "pattern",
+            Side::Displays => "display",
+        };
+        write!(f, "{} {} ({})", side, self.index, self.span)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePanelError {
+    MissingSeparator,
+    // A second separator
+    ExtraSeparator(Span),
+    NoDisplays,
+    InvalidSegment {
+        token: Token,
+        error: ParseSegmentError,
+    },
+    // The same pattern as an earlier one
+    DuplicatePattern {
+        token: Token,
+        first: Token,
+    },
+}
+
+impl fmt::Display for ParsePanelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePanelError::MissingSeparator => write!(f, "no '|' between patterns and displays"),
+            ParsePanelError::ExtraSeparator(span) => write!(f, "another '|' at {}", span),
+            ParsePanelError::NoDisplays => write!(f, "no displays after the '|'"),
+            ParsePanelError::InvalidSegment { token, error } => write!(f, "{}: {}", token, error),
+            ParsePanelError::DuplicatePattern { token, first } => {
+                write!(f, "{} is the same as {}", token, first)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLineError {
+    pub line: usize,
+    pub error: ParsePanelError,
+}
+
+impl fmt::Display for ParseLineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+// The span of characters begin to end, counting from 0, with end excluded
+fn span(begin: usize, end: usize) -> Span {
+    Span {
+        start: begin + 1,
+        end,
+    }
+}
+
+// Splits a line into words and separators, with their spans
+fn tokenize(s: &str) -> Vec<(&str, Span)> {
+    let mut tokens = Vec::new();
+    // The byte offset and column where the current word starts
+    let mut start = None;
+    for (col, (i, c)) in s.char_indices().enumerate() {
+        if c.is_whitespace() || c == '|' {
+            if let Some((begin, begin_col)) = start.take() {
+                tokens.push((&s[begin..i], span(begin_col, col)));
+            }
+            if c == '|' {
+                tokens.push(("|", span(col, col + 1)));
+            }
+        } else if start.is_none() {
+            start = Some((i, col));
+        }
+    }
+    if let Some((begin, begin_col)) = start {
+        tokens.push((&s[begin..], span(begin_col, s.chars().count())));
+    }
+    tokens
+}
+
+fn parse_side(tokens: &[(&str, Span)], side: Side) -> Result<Vec<SegmentSet>, ParsePanelError> {
+    let mut sets: Vec<SegmentSet> = Vec::with_capacity(tokens.len());
+    for (i, &(word, span)) in tokens.iter().enumerate() {
+        let token = Token {
+            side,
+            index: i + 1,
+            span,
+        };
+        let set = word
+            .parse::<SegmentSet>()
+            .map_err(|error| ParsePanelError::InvalidSegment { token, error })?;
+        if side == Side::Patterns {
+            if let Some(first) = sets.iter().position(|&s| s == set) {
+                return Err(ParsePanelError::DuplicatePattern {
+                    token,
+                    first: Token {
+                        side,
+                        index: first + 1,
+                        span: tokens[first].1,
+                    },
+                });
+            }
+        }
+        sets.push(set);
+    }
+    Ok(sets)
+}
+
+impl FromStr for Panel {
+    type Err = ParsePanelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(s);
+        let mut separators = tokens.iter().enumerate().filter(|(_, (w, _))| *w == "|");
+        let (split, _) = separators.next().ok_or(ParsePanelError::MissingSeparator)?;
+        if let Some((_, &(_, span))) = separators.next() {
+            return Err(ParsePanelError::ExtraSeparator(span));
+        }
+        let inputs = parse_side(&tokens[..split], Side::Patterns)?;
+        let displays = parse_side(&tokens[split + 1..], Side::Displays)?;
+        if displays.is_empty() {
+            return Err(ParsePanelError::NoDisplays);
+        }
+        Ok(Panel { inputs, displays })
+    }
+}
+
+// Parses a panel from every non-blank line, with the line it came from counting from 1, reporting
+// every line that fails
+pub fn parse_panels<S: AsRef<str>>(
+    lines: impl Iterator<Item = S>,
+) -> Result<Vec<(usize, Panel)>, Vec<ParseLineError>> {
+    let mut panels = Vec::new();
+    let mut errors = Vec::new();
+    for (num, line) in lines.enumerate() {
+        let line = line.as_ref();
+        if line.trim().is_empty() {
+            continue;
+        }
+        match line.parse::<Panel>() {
+            Ok(panel) => panels.push((num + 1, panel)),
+            Err(error) => errors.push(ParseLineError {
+                line: num + 1,
+                error,
+            }),
+        }
+    }
+    if errors.is_empty() {
+        Ok(panels)
+    } else {
+        Err(errors)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::Grid;
+
+/*
+Labels every basin in one pass over the grid with union-find: each cell below the wall height is
+joined to its right and lower neighbours below the wall height, so each set is a basin. A second
+union-find joins neighbours of equal height into level areas, and an area with no lower neighbour
+is a low. A single cell low is a low point in the puzzle's sense; a larger one is a plateau, which
+has no strict low point but still collects its basin's flow.
+*/
+
+#[derive(Debug, Clone)]
+pub(crate) struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    pub(crate) fn new(n: usize) -> Self {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    pub(crate) fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            // Path halving
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    pub(crate) fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
+
+// A connected area of equal height with no lower neighbour
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Low {
+    pub height: usize,
+    // In row-major order, as (x, y)
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Low {
+    pub fn is_plateau(&self) -> bool {
+        self.cells.len() > 1
+    }
+}
+
+// A connected area of cells below the wall height with equal height
+#[derive(Debug, Clone)]
+pub(crate) struct Area {
+    pub(crate) height: usize,
+    pub(crate) cells: Vec<(usize, usize)>,
+    // Whether any neighbouring cell is lower
+    pub(crate) lower: bool,
+}
+
+// Splits the cells below the wall height into level areas, numbered in reading order. Also
+// returns the area of each cell, by index, or None for walls.
+pub(crate) fn level_areas(grid: &Grid) -> (Vec<Area>, Vec<Option<usize>>) {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let index = |x: usize, y: usize| y * cols + x;
+    let mut levels = UnionFind::new(rows * cols);
+    for y in 0..rows {
+        for x in 0..cols {
+            let height = grid.get(x, y).value;
+            let right = (x + 1 < cols).then(|| (x + 1, y));
+            let down = (y + 1 < rows).then(|| (x, y + 1));
+            for (nx, ny) in right.into_iter().chain(down) {
+                if grid.get(nx, ny).value == height {
+                    levels.union(index(x, y), index(nx, ny));
+                }
+            }
+        }
+    }
+
+    let mut area_of_root = vec![None; rows * cols];
+    let mut area_of = vec![None; rows * cols];
+    let mut areas: Vec<Area> = Vec::new();
+    for y in 0..rows {
+        for x in 0..cols {
+            let height = grid.get(x, y).value;
+            if grid.is_wall(x, y) {
+                continue;
+            }
+            let root = levels.find(index(x, y));
+            let area = *area_of_root[root].get_or_insert_with(|| {
+                areas.push(Area {
+                    height,
+                    cells: Vec::new(),
+                    lower: false,
+                });
+                areas.len() - 1
+            });
+            area_of[index(x, y)] = Some(area);
+            areas[area].cells.push((x, y));
+            areas[area].lower |= grid
+                .neighbours(x, y)
+                .any(|(nx, ny)| grid.get(nx, ny).value < height);
+        }
+    }
+    (areas, area_of)
+}
+
+#[derive(Clone)]
+pub struct Basins {
+    // The basin of each cell, or None for walls
+    labels: app::grid::Grid<Option<usize>>,
+    sizes: Vec<usize>,
+    lows: Vec<Vec<Low>>,
+}
+
+impl Basins {
+    // Basins are numbered in the order their first cell appears, reading row by row
+    pub fn new(grid: &Grid) -> Self {
+        let (rows, cols) = (grid.rows(), grid.cols());
+        let index = |x: usize, y: usize| y * cols + x;
+        let mut basins = UnionFind::new(rows * cols);
+        for y in 0..rows {
+            for x in 0..cols {
+                let right = (x + 1 < cols).then(|| (x + 1, y));
+                let down = (y + 1 < rows).then(|| (x, y + 1));
+                for (nx, ny) in right.into_iter().chain(down) {
+                    if !grid.is_wall(x, y) && !grid.is_wall(nx, ny) {
+                        basins.union(index(x, y), index(nx, ny));
+                    }
+                }
+            }
+        }
+
+        let mut label_of = vec![None; rows * cols];
+        let mut labels = app::grid::Grid::new(rows, cols);
+        let mut sizes = Vec::new();
+        for y in 0..rows {
+            for x in 0..cols {
+                if grid.is_wall(x, y) {
+                    continue;
+                }
+                let root = basins.find(index(x, y));
+                let label = *label_of[root].get_or_insert_with(|| {
+                    sizes.push(0);
+                    sizes.len() - 1
+                });
+                labels[y][x] = Some(label);
+                sizes[label] += 1;
+            }
+        }
+
+        let mut lows = vec![Vec::new(); sizes.len()];
+        for area in level_areas(grid).0 {
+            if !area.lower {
+                let (x, y) = area.cells[0];
+                if let Some(label) = labels[y][x] {
+                    lows[label].push(Low {
+                        height: area.height,
+                        cells: area.cells,
+                    });
+                }
+            }
+        }
+
+        Basins {
+            labels,
+            sizes,
+            lows,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty()
+    }
+
+    pub fn labels(&self) -> &app::grid::Grid<Option<usize>> {
+        &self.labels
+    }
+
+    pub fn label(&self, x: usize, y: usize) -> Option<usize> {
+        self.labels[y][x]
+    }
+
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    pub fn size(&self, basin: usize) -> usize {
+        self.sizes[basin]
+    }
+
+    // The lows in a basin, which the puzzle expects to be a single low point
+    pub fn lows(&self, basin: usize) -> &[Low] {
+        &self.lows[basin]
+    }
+
+    // Basin sizes, largest first
+    pub fn largest(&self, n: usize) -> Vec<usize> {
+        let mut sizes = self.sizes.clone();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(n);
+        sizes
+    }
+}
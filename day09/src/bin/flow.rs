@@ -0,0 +1,58 @@
+use std::env;
+use std::process;
+
+use day09::Grid;
+
+const USAGE: &str = "Usage: flow [--wall HEIGHT] [FILE]";
+
+// Maps where water flows and reports the cells where the puzzle's assumptions break down
+fn main() {
+    let usage = || -> ! {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let mut wall_height = day09::WALL_HEIGHT;
+    let mut path = "input.txt".to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--wall" => {
+                wall_height = args
+                    .next()
+                    .and_then(|h| h.parse::<usize>().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            _ => path = arg,
+        }
+    }
+
+    let mut grid = Grid::from_lines(app::read_lines(&path)).unwrap_or_else(|err| {
+        eprintln!("Invalid grid: {:?}", err);
+        process::exit(1);
+    });
+    grid.wall_height = wall_height;
+
+    let basins = grid.basins();
+    let flow = grid.flow();
+    print!("{}", flow.render());
+
+    let plateaus = flow.lows().iter().filter(|l| l.is_plateau()).count();
+    println!(
+        "{} basins with {} lows, of which {} are plateaus",
+        basins.len(),
+        flow.lows().len(),
+        plateaus
+    );
+    let shared = (0..basins.len())
+        .filter(|&b| basins.lows(b).len() > 1)
+        .count();
+    println!("{} basins have more than one low", shared);
+
+    let divides = flow.divides();
+    println!("{} cells drain to more than one low", divides.len());
+    for &(x, y) in divides.iter().take(10) {
+        let lows: Vec<String> = flow.drains(x, y).iter().map(|l| l.to_string()).collect();
+        println!("  ({}, {}) drains to lows {}", x, y, lows.join(", "));
+    }
+    println!("{} cells lie on ridges", flow.ridges(&grid).len());
+}
@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+
+use crate::basins::level_areas;
+use crate::{Grid, Low};
+
+/*
+Works out where water on each cell goes. Every cell below the wall height flows to its lowest
+neighbour, if any neighbour is lower, taking the first of equally low neighbours in the order left,
+right, up, down. On a flat area that isn't a low, cells flow towards the nearest cell on the area's
+edge that can flow downhill. Following these directions leads every cell to a single low, its
+catchment.
+
+Water can take any downhill path though, not just the steepest, so a cell drains to every low
+reachable by repeatedly stepping to a lower area. The puzzle assumes each cell drains to just one,
+so cells that drain to more than one are the divides where that assumption fails. Ridges are the
+cells on the high side of a boundary between catchments.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    // The direction from one cell to a neighbouring cell
+    fn between((x, y): (usize, usize), (nx, ny): (usize, usize)) -> Direction {
+        if nx < x {
+            Direction::Left
+        } else if nx > x {
+            Direction::Right
+        } else if ny < y {
+            Direction::Up
+        } else {
+            Direction::Down
+        }
+    }
+
+    // The neighbouring cell in this direction, which must be within the grid
+    pub fn step(self, x: usize, y: usize) -> (usize, usize) {
+        match self {
+            Direction::Left => (x - 1, y),
+            Direction::Right => (x + 1, y),
+            Direction::Up => (x, y - 1),
+            Direction::Down => (x, y + 1),
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Left => '<',
+            Direction::Right => '>',
+            Direction::Up => '^',
+            Direction::Down => 'v',
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Flow {
+    lows: Vec<Low>,
+    // None for walls and cells in lows
+    directions: app::grid::Grid<Option<Direction>>,
+    // The low each cell flows to by its directions, or None for walls
+    catchments: app::grid::Grid<Option<usize>>,
+    // The level area of each cell by index, and every low each area drains to
+    area_of: Vec<Option<usize>>,
+    drains: Vec<Vec<usize>>,
+}
+
+impl Flow {
+    // Lows are numbered in the order their first cell appears, reading row by row
+    pub fn new(grid: &Grid) -> Self {
+        let (rows, cols) = (grid.rows(), grid.cols());
+        let index = |x: usize, y: usize| y * cols + x;
+        let (areas, area_of) = level_areas(grid);
+        let height = |(x, y): (usize, usize)| grid.get(x, y).value;
+
+        let mut low_of_area = vec![None; areas.len()];
+        let mut lows = Vec::new();
+        for (i, area) in areas.iter().enumerate() {
+            if !area.lower {
+                low_of_area[i] = Some(lows.len());
+                lows.push(Low {
+                    height: area.height,
+                    cells: area.cells.clone(),
+                });
+            }
+        }
+
+        // Head for the lowest neighbour, then spread across flats from their downhill edges
+        let mut directions = app::grid::Grid::new(rows, cols);
+        let mut pending = VecDeque::new();
+        for y in 0..rows {
+            for x in 0..cols {
+                if grid.is_wall(x, y) {
+                    continue;
+                }
+                let lowest = grid
+                    .neighbours(x, y)
+                    .filter(|&n| height(n) < height((x, y)))
+                    .enumerate()
+                    .min_by_key(|&(i, n)| (height(n), i));
+                if let Some((_, n)) = lowest {
+                    directions[y][x] = Some(Direction::between((x, y), n));
+                    pending.push_back((x, y));
+                }
+            }
+        }
+        while let Some((x, y)) = pending.pop_front() {
+            for (nx, ny) in grid.neighbours(x, y) {
+                let flat = area_of[index(nx, ny)].is_some_and(|a| low_of_area[a].is_none());
+                if flat && height((nx, ny)) == height((x, y)) && directions[ny][nx].is_none() {
+                    directions[ny][nx] = Some(Direction::between((nx, ny), (x, y)));
+                    pending.push_back((nx, ny));
+                }
+            }
+        }
+
+        let mut catchments: app::grid::Grid<Option<usize>> = app::grid::Grid::new(rows, cols);
+        for y in 0..rows {
+            for x in 0..cols {
+                if grid.is_wall(x, y) {
+                    continue;
+                }
+                let mut path = Vec::new();
+                let mut cell = (x, y);
+                let low = loop {
+                    if let Some(low) = catchments[cell.1][cell.0] {
+                        break low;
+                    }
+                    path.push(cell);
+                    match directions[cell.1][cell.0] {
+                        Some(d) => cell = d.step(cell.0, cell.1),
+                        None => {
+                            break low_of_area[area_of[index(cell.0, cell.1)].unwrap()].unwrap()
+                        }
+                    }
+                };
+                for (px, py) in path {
+                    catchments[py][px] = Some(low);
+                }
+            }
+        }
+
+        // Lower areas are done first, so each area can gather the lows its neighbours drain to
+        let mut order: Vec<usize> = (0..areas.len()).collect();
+        order.sort_by_key(|&a| areas[a].height);
+        let mut drains: Vec<Vec<usize>> = vec![Vec::new(); areas.len()];
+        for a in order {
+            if let Some(low) = low_of_area[a] {
+                drains[a] = vec![low];
+                continue;
+            }
+            let mut reached = Vec::new();
+            for &(x, y) in areas[a].cells.iter() {
+                for n in grid.neighbours(x, y) {
+                    if height(n) < areas[a].height {
+                        let below = area_of[index(n.0, n.1)].unwrap();
+                        reached.extend_from_slice(&drains[below]);
+                    }
+                }
+            }
+            reached.sort_unstable();
+            reached.dedup();
+            drains[a] = reached;
+        }
+
+        Flow {
+            lows,
+            directions,
+            catchments,
+            area_of,
+            drains,
+        }
+    }
+
+    pub fn lows(&self) -> &[Low] {
+        &self.lows
+    }
+
+    pub fn direction(&self, x: usize, y: usize) -> Option<Direction> {
+        self.directions[y][x]
+    }
+
+    pub fn catchment(&self, x: usize, y: usize) -> Option<usize> {
+        self.catchments[y][x]
+    }
+
+    // Every low the cell can drain to, which is empty for walls
+    pub fn drains(&self, x: usize, y: usize) -> &[usize] {
+        let (_, cols) = self.catchments.size();
+        match self.area_of[y * cols + x] {
+            Some(area) => &self.drains[area],
+            None => &[],
+        }
+    }
+
+    // The cells that drain to more than one low
+    pub fn divides(&self) -> Vec<(usize, usize)> {
+        self.catchments
+            .iter()
+            .map(|(x, y, _)| (x, y))
+            .filter(|&(x, y)| self.drains(x, y).len() > 1)
+            .collect()
+    }
+
+    // The cells next to a cell of another catchment that's no higher
+    pub fn ridges(&self, grid: &Grid) -> Vec<(usize, usize)> {
+        self.catchments
+            .iter()
+            .filter_map(|(x, y, catchment)| catchment.map(|c| (x, y, c)))
+            .filter(|&(x, y, c)| {
+                grid.neighbours(x, y).any(|(nx, ny)| {
+                    self.catchments[ny][nx].is_some_and(|other| other != c)
+                        && grid.get(nx, ny).value <= grid.get(x, y).value
+                })
+            })
+            .map(|(x, y, _)| (x, y))
+            .collect()
+    }
+
+    // A map of the flow: walls are '#', lows are 'o', divides are '*', and other cells show their
+    // direction
+    pub fn render(&self) -> String {
+        let (rows, cols) = self.catchments.size();
+        let mut map = String::with_capacity(rows * (cols + 1));
+        for y in 0..rows {
+            for x in 0..cols {
+                map.push(match (self.catchments[y][x], self.directions[y][x]) {
+                    (None, _) => '#',
+                    (_, None) => 'o',
+                    _ if self.drains(x, y).len() > 1 => '*',
+                    (_, Some(d)) => d.arrow(),
+                });
+            }
+            map.push('\n');
+        }
+        map
+    }
+}
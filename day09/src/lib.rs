@@ -0,0 +1,148 @@
+pub mod basins;
+pub mod flow;
+
+pub use basins::{Basins, Low};
+pub use flow::{Direction, Flow};
+
+// Cells this high are walls between basins, unless the grid says otherwise
+pub const WALL_HEIGHT: usize = 9;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    NoData,
+    InvalidData,
+    InconsistentWidth,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub value: usize,
+}
+
+pub struct Grid {
+    cells: Vec<Cell>,
+    rows: usize,
+    cols: usize,
+    // Cells at least this high are walls
+    pub wall_height: usize,
+}
+
+impl<'a> Grid {
+    pub fn get(&'a self, x: usize, y: usize) -> &'a Cell {
+        &self.cells[y * self.cols + x]
+    }
+
+    pub fn is_wall(&self, x: usize, y: usize) -> bool {
+        self.get(x, y).value >= self.wall_height
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    // The cells above, below, left and right that are within the grid
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (rows, cols) = (self.rows, self.cols);
+        [
+            (x > 0).then(|| (x - 1, y)),
+            (x + 1 < cols).then(|| (x + 1, y)),
+            (y > 0).then(|| (x, y - 1)),
+            (y + 1 < rows).then(|| (x, y + 1)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    pub fn basins(&self) -> Basins {
+        Basins::new(self)
+    }
+
+    pub fn flow(&self) -> Flow {
+        Flow::new(self)
+    }
+
+    // Strict low points only, so plateaus are missed; see Basins::lows for those
+    pub fn lowest(&self) -> Vec<(usize, usize)> {
+        let mut result = Vec::new();
+        for y in 0..self.rows {
+            for x in 0..self.cols {
+                let cell = self.get(x, y);
+                if x > 0 && cell.value >= self.get(x - 1, y).value {
+                    continue;
+                }
+                if x + 1 < self.cols && cell.value >= self.get(x + 1, y).value {
+                    continue;
+                }
+                if y > 0 && cell.value >= self.get(x, y - 1).value {
+                    continue;
+                }
+                if y + 1 < self.rows && cell.value >= self.get(x, y + 1).value {
+                    continue;
+                }
+                result.push((x, y));
+            }
+        }
+        result
+    }
+
+    pub fn from_lines<S: AsRef<str>>(
+        mut lines: impl Iterator<Item = S>,
+    ) -> Result<Grid, ParseGridError> {
+        let first = lines.next().ok_or(ParseGridError::NoData)?;
+        let mut cells = row_to_cells(first.as_ref())?;
+        let cols = cells.len();
+        let mut rows = 1;
+        for line in lines {
+            let mut row = row_to_cells(line.as_ref())?;
+            if row.len() != cols {
+                return Err(ParseGridError::InconsistentWidth);
+            }
+            cells.append(&mut row);
+            rows += 1;
+        }
+        Ok(Grid {
+            cells,
+            rows,
+            cols,
+            wall_height: WALL_HEIGHT,
+        })
+    }
+}
+
+fn row_to_cells(s: &str) -> Result<Vec<Cell>, ParseGridError> {
+    s.chars()
+        .map(|c| {
+            c.to_digit(10)
+                .map(|d| Cell { value: d as usize })
+                .ok_or(ParseGridError::InvalidData)
+        })
+        .collect()
+}
+
+fn read_grid(path: &str) -> Grid {
+    Grid::from_lines(app::read_lines(path)).unwrap_or_else(|err| panic!("Invalid grid: {:?}", err))
+}
+
+// The sum of the risk levels of the low points
+pub fn part_a(path: &str) -> String {
+    let grid = read_grid(path);
+    grid.lowest()
+        .iter()
+        .map(|&(x, y)| grid.get(x, y).value + 1)
+        .sum::<usize>()
+        .to_string()
+}
+
+// The product of the sizes of the three largest basins
+pub fn part_b(path: &str) -> String {
+    read_grid(path)
+        .basins()
+        .largest(3)
+        .iter()
+        .product::<usize>()
+        .to_string()
+}
@@ -0,0 +1,77 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use day10::{Brackets, Checker, ScoringScheme, Status};
+
+const USAGE: &str =
+    "Usage: lint [--pairs PAIRS] [--scheme FILE] [--ignore-other] [--all] [FILE...]";
+
+// Reports the corrupt and incomplete lines in each file, with their scores
+fn main() {
+    let usage = || -> ! {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let fail = |what: &str, err: &dyn std::fmt::Display| -> ! {
+        eprintln!("{}: {}", what, err);
+        process::exit(1);
+    };
+    let mut brackets = Brackets::default();
+    let mut scheme = ScoringScheme::puzzle();
+    let mut ignore_other = false;
+    let mut all = false;
+    let mut paths = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--pairs" => {
+                let pairs = args.next().unwrap_or_else(|| usage());
+                brackets = pairs
+                    .parse()
+                    .unwrap_or_else(|err| fail("Invalid pairs", &err));
+            }
+            "--scheme" => {
+                let path = args.next().unwrap_or_else(|| usage());
+                let text = fs::read_to_string(&path).unwrap_or_else(|err| fail(&path, &err));
+                scheme = text
+                    .parse()
+                    .unwrap_or_else(|err| fail("Invalid scheme", &err));
+            }
+            "--ignore-other" => ignore_other = true,
+            "--all" => all = true,
+            _ => paths.push(arg),
+        }
+    }
+    if paths.is_empty() {
+        paths.push("input.txt".to_string());
+    }
+
+    let mut checker =
+        Checker::new(brackets, scheme).unwrap_or_else(|err| fail("Invalid scheme", &err));
+    checker.ignore_other = ignore_other;
+    let mut reports = Vec::new();
+    for path in paths.iter() {
+        let text = fs::read_to_string(path).unwrap_or_else(|err| fail(path, &err));
+        for report in checker.check_lines(text.lines()) {
+            if all || report.status != Status::Valid {
+                println!("{}:{}: {}", path, report.line, report.status);
+            }
+            reports.push(report);
+        }
+    }
+
+    let count = |f: fn(&Status) -> bool| reports.iter().filter(|r| f(&r.status)).count();
+    println!(
+        "{} lines: {} valid, {} corrupt, {} incomplete, {} unexpected",
+        reports.len(),
+        count(|s| matches!(s, Status::Valid)),
+        count(|s| matches!(s, Status::Corrupt { .. })),
+        count(|s| matches!(s, Status::Incomplete { .. })),
+        count(|s| matches!(s, Status::Unexpected { .. })),
+    );
+    println!("Corruption total {}", day10::corruption_total(&reports));
+    if let Some(median) = day10::completion_median(&reports) {
+        println!("Completion median {}", median);
+    }
+}
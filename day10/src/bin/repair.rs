@@ -0,0 +1,76 @@
+use std::env;
+use std::process;
+
+use day10::{Brackets, ChunkError, Navigator};
+
+const USAGE: &str = "Usage: repair [--pairs PAIRS] [FILE]";
+
+// Reports only the first error of a line too long to repair, as the puzzle checks lines
+fn first_error(brackets: &Brackets, line: &str) -> Option<String> {
+    let mut nav = Navigator::with_brackets(brackets.clone());
+    for (offset, c) in line.char_indices() {
+        match nav.parse_char(c) {
+            Ok(()) => continue,
+            Err(ChunkError::UnexpectedInput(_)) => {
+                return Some(format!("unexpected '{}' at byte {}", c, offset))
+            }
+            Err(ChunkError::EmptyClose(_)) => {
+                return Some(format!("'{}' at byte {} closes nothing", c, offset))
+            }
+            Err(ChunkError::IncorrectClose { expected, .. }) => {
+                let expected = brackets.closer(expected).unwrap();
+                return Some(format!(
+                    "expected '{}' but found '{}' at byte {}",
+                    expected, c, offset
+                ));
+            }
+        }
+    }
+    let suffix = nav.suffix();
+    (!suffix.is_empty()).then(|| format!("needs \"{}\" at the end", suffix))
+}
+
+// Reports every error in each line, with the fewest edits that balance it
+fn main() {
+    let usage = || -> ! {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let mut brackets = Brackets::default();
+    let mut path = "input.txt".to_string();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--pairs" => {
+                let pairs = args.next().unwrap_or_else(|| usage());
+                brackets = pairs.parse().unwrap_or_else(|err| {
+                    eprintln!("Invalid pairs: {}", err);
+                    process::exit(1);
+                })
+            }
+            _ => path = arg,
+        }
+    }
+
+    let mut broken = 0;
+    for (num, line) in app::read_lines(&path).enumerate() {
+        let Some(parse) = day10::parse(&brackets, &line) else {
+            if let Some(error) = first_error(&brackets, &line) {
+                broken += 1;
+                println!("line {}: too long to repair, first error:", num + 1);
+                println!("  {}", error);
+            }
+            continue;
+        };
+        if parse.is_valid() {
+            continue;
+        }
+        broken += 1;
+        println!("line {}: {} errors", num + 1, parse.errors.len());
+        for error in parse.errors.iter() {
+            println!("  {}", error);
+        }
+        println!("  repaired: {}", parse.repaired(&line));
+    }
+    println!("{} lines need repair", broken);
+}
@@ -0,0 +1,183 @@
+pub mod scoring;
+pub mod syntax;
+
+use std::fmt;
+use std::str::FromStr;
+
+pub use scoring::{
+    completion_median, corruption_total, Checker, Report, SchemeError, ScoringScheme, Status,
+};
+pub use syntax::{parse, Chunk, Edit, Parse, SyntaxError, MAX_REPAIR_LEN};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BracketError {
+    // The character is already used by another pair
+    Repeated(char),
+    // An opening character with no closing character after it
+    Unpaired(char),
+}
+
+impl fmt::Display for BracketError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BracketError::Repeated(c) => write!(f, "'{}' is in more than one pair", c),
+            BracketError::Unpaired(c) => write!(f, "'{}' has nothing to close it", c),
+        }
+    }
+}
+
+// Pairs of opening and closing characters, each character in only one pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Brackets {
+    pairs: Vec<(char, char)>,
+}
+
+impl Brackets {
+    pub fn new(pairs: &[(char, char)]) -> Result<Self, BracketError> {
+        let mut brackets = Brackets {
+            pairs: Vec::with_capacity(pairs.len()),
+        };
+        for &(open, close) in pairs {
+            if open == close || brackets.contains(open) {
+                return Err(BracketError::Repeated(open));
+            }
+            if brackets.contains(close) {
+                return Err(BracketError::Repeated(close));
+            }
+            brackets.pairs.push((open, close));
+        }
+        Ok(brackets)
+    }
+
+    pub fn pairs(&self) -> &[(char, char)] {
+        &self.pairs
+    }
+
+    fn contains(&self, c: char) -> bool {
+        self.pairs
+            .iter()
+            .any(|&(open, close)| c == open || c == close)
+    }
+
+    // The closing character for an opening one
+    pub fn closer(&self, open: char) -> Option<char> {
+        self.pairs.iter().find(|p| p.0 == open).map(|p| p.1)
+    }
+
+    // The opening character for a closing one
+    pub fn opener(&self, close: char) -> Option<char> {
+        self.pairs.iter().find(|p| p.1 == close).map(|p| p.0)
+    }
+}
+
+// The puzzle's (), [], {} and <>
+impl Default for Brackets {
+    fn default() -> Self {
+        Brackets::new(&[('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]).unwrap()
+    }
+}
+
+// Each opening character followed by its closing character, as in "()[]"
+impl FromStr for Brackets {
+    type Err = BracketError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        if !chars.len().is_multiple_of(2) {
+            return Err(BracketError::Unpaired(chars[chars.len() - 1]));
+        }
+        let pairs: Vec<(char, char)> = chars.chunks(2).map(|p| (p[0], p[1])).collect();
+        Brackets::new(&pairs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkError {
+    // The stack was empty. Errors report the opening character matching the offending close.
+    EmptyClose(char),
+    IncorrectClose { expected: char, actual: char },
+    UnexpectedInput(char),
+}
+
+pub struct Navigator {
+    brackets: Brackets,
+    stack: Vec<char>,
+}
+
+impl Default for Navigator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Navigator {
+    pub fn new() -> Navigator {
+        Navigator::with_brackets(Brackets::default())
+    }
+
+    pub fn with_brackets(brackets: Brackets) -> Navigator {
+        Navigator {
+            brackets,
+            stack: Vec::new(),
+        }
+    }
+
+    pub fn parse_char(&mut self, c: char) -> Result<(), ChunkError> {
+        if self.brackets.closer(c).is_some() {
+            self.stack.push(c);
+            return Ok(());
+        }
+        match self.brackets.opener(c) {
+            Some(open) => self.pop_char(open),
+            None => Err(ChunkError::UnexpectedInput(c)),
+        }
+    }
+
+    fn pop_char(&mut self, c: char) -> Result<(), ChunkError> {
+        let top = self.stack.last().ok_or(ChunkError::EmptyClose(c))?;
+        if *top == c {
+            self.stack.pop();
+            Ok(())
+        } else {
+            Err(ChunkError::IncorrectClose {
+                expected: *top,
+                actual: c,
+            })
+        }
+    }
+
+    pub fn parse_str(&mut self, s: &str) -> Result<(), ChunkError> {
+        s.chars().try_for_each(|c| self.parse_char(c))
+    }
+
+    pub fn suffix(&self) -> String {
+        self.stack
+            .iter()
+            .rev()
+            .filter_map(|&c| self.brackets.closer(c))
+            .collect()
+    }
+}
+
+fn check_file(path: &str) -> Vec<Report> {
+    let reports = Checker::default().check_lines(app::read_lines(path));
+    if let Some(report) = reports
+        .iter()
+        .find(|r| matches!(r.status, Status::Unexpected { .. }))
+    {
+        panic!("line {}: {}", report.line, report.status);
+    }
+    reports
+}
+
+// The total score of the corrupt lines
+pub fn part_a(path: &str) -> String {
+    corruption_total(&check_file(path)).to_string()
+}
+
+// The median score of the incomplete lines
+pub fn part_b(path: &str) -> String {
+    completion_median(&check_file(path))
+        .expect("No line is incomplete")
+        .to_string()
+}
@@ -0,0 +1,322 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Brackets, ChunkError, Navigator};
+
+/*
+Checks lines left to right, stopping at the first closing character that doesn't match, as the
+puzzle does. A corrupt line scores the points for that character, and an incomplete line scores
+the closing characters that complete it, each in turn multiplying the score so far by the base and
+adding its points. Scores saturate rather than overflow on very long lines.
+
+A scheme can be read from text, one entry per line, with blank lines and lines starting with '#'
+skipped:
+
+    base 5
+    ) 3 1
+    ] 57 2
+
+where each entry is a closing character, its corruption points and its completion points.
+*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemeError {
+    // The line of the scheme, counting from 1, isn't a base or an entry
+    Malformed(usize),
+    // The closing character has more than one entry
+    Repeated(char),
+    // The closing character has no entry
+    Unscored(char),
+}
+
+impl fmt::Display for SchemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemeError::Malformed(line) => write!(f, "line {} isn't a base or an entry", line),
+            SchemeError::Repeated(c) => write!(f, "'{}' is scored more than once", c),
+            SchemeError::Unscored(c) => write!(f, "'{}' has no score", c),
+        }
+    }
+}
+
+// The points for each closing character
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoringScheme {
+    base: usize,
+    // The closing character, its corruption points and its completion points
+    scores: Vec<(char, usize, usize)>,
+}
+
+impl ScoringScheme {
+    pub fn new(base: usize) -> Self {
+        ScoringScheme {
+            base,
+            scores: Vec::new(),
+        }
+    }
+
+    pub fn set(
+        &mut self,
+        close: char,
+        corruption: usize,
+        completion: usize,
+    ) -> Result<(), SchemeError> {
+        if self.scores.iter().any(|s| s.0 == close) {
+            return Err(SchemeError::Repeated(close));
+        }
+        self.scores.push((close, corruption, completion));
+        Ok(())
+    }
+
+    // The puzzle's scores for ), ], } and >
+    pub fn puzzle() -> Self {
+        let mut scheme = ScoringScheme::new(5);
+        for (close, corruption, completion) in
+            [(')', 3, 1), (']', 57, 2), ('}', 1197, 3), ('>', 25137, 4)]
+        {
+            scheme.set(close, corruption, completion).unwrap();
+        }
+        scheme
+    }
+
+    pub fn base(&self) -> usize {
+        self.base
+    }
+
+    pub fn corruption(&self, close: char) -> Option<usize> {
+        self.scores.iter().find(|s| s.0 == close).map(|s| s.1)
+    }
+
+    pub fn completion(&self, close: char) -> Option<usize> {
+        self.scores.iter().find(|s| s.0 == close).map(|s| s.2)
+    }
+
+    // The score for a string of closing characters, or None if one has no entry
+    pub fn completion_score(&self, closes: &str) -> Option<usize> {
+        closes.chars().try_fold(0usize, |acc, c| {
+            let points = self.completion(c)?;
+            Some(acc.saturating_mul(self.base).saturating_add(points))
+        })
+    }
+
+    // Checks every closing character has an entry
+    pub fn covers(&self, brackets: &Brackets) -> Result<(), SchemeError> {
+        match brackets
+            .pairs()
+            .iter()
+            .find(|p| self.corruption(p.1).is_none())
+        {
+            Some(&(_, close)) => Err(SchemeError::Unscored(close)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for ScoringScheme {
+    fn default() -> Self {
+        ScoringScheme::puzzle()
+    }
+}
+
+impl FromStr for ScoringScheme {
+    type Err = SchemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut base = None;
+        let mut entries = Vec::new();
+        for (num, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = SchemeError::Malformed(num + 1);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words[..] {
+                ["base", n] if base.is_none() => {
+                    base = Some(n.parse::<usize>().map_err(|_| malformed)?);
+                }
+                [close, corruption, completion] => {
+                    let mut chars = close.chars();
+                    let close = match (chars.next(), chars.next()) {
+                        (Some(c), None) => c,
+                        _ => return Err(malformed),
+                    };
+                    let corruption = corruption.parse::<usize>().map_err(|_| malformed.clone())?;
+                    let completion = completion.parse::<usize>().map_err(|_| malformed)?;
+                    entries.push((close, corruption, completion));
+                }
+                _ => return Err(malformed),
+            }
+        }
+        let mut scheme = ScoringScheme::new(base.unwrap_or(5));
+        for (close, corruption, completion) in entries {
+            scheme.set(close, corruption, completion)?;
+        }
+        Ok(scheme)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Valid,
+    // The first closing character that doesn't match, and the one that would have
+    Corrupt {
+        offset: usize,
+        found: char,
+        expected: Option<char>,
+        score: usize,
+    },
+    // The closing characters that complete the line
+    Incomplete {
+        completion: String,
+        score: usize,
+    },
+    // A character that isn't a bracket
+    Unexpected {
+        offset: usize,
+        found: char,
+    },
+}
+
+impl Status {
+    pub fn score(&self) -> usize {
+        match self {
+            Status::Corrupt { score, .. } | Status::Incomplete { score, .. } => *score,
+            Status::Valid | Status::Unexpected { .. } => 0,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Valid => write!(f, "valid"),
+            Status::Corrupt {
+                offset,
+                found,
+                expected: Some(expected),
+                score,
+            } => write!(
+                f,
+                "corrupt, expected '{}' but found '{}' at byte {}, score {}",
+                expected, found, offset, score
+            ),
+            Status::Corrupt {
+                offset,
+                found,
+                expected: None,
+                score,
+            } => write!(
+                f,
+                "corrupt, '{}' at byte {} closes nothing, score {}",
+                found, offset, score
+            ),
+            Status::Incomplete { completion, score } => {
+                write!(f, "incomplete, needs \"{}\", score {}", completion, score)
+            }
+            Status::Unexpected { offset, found } => {
+                write!(f, "unexpected '{}' at byte {}", found, offset)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    // Counting from 1
+    pub line: usize,
+    pub status: Status,
+}
+
+#[derive(Debug, Clone)]
+pub struct Checker {
+    brackets: Brackets,
+    scheme: ScoringScheme,
+    // Skip characters that aren't brackets instead of reporting them
+    pub ignore_other: bool,
+}
+
+impl Checker {
+    pub fn new(brackets: Brackets, scheme: ScoringScheme) -> Result<Self, SchemeError> {
+        scheme.covers(&brackets)?;
+        Ok(Checker {
+            brackets,
+            scheme,
+            ignore_other: false,
+        })
+    }
+
+    pub fn brackets(&self) -> &Brackets {
+        &self.brackets
+    }
+
+    pub fn scheme(&self) -> &ScoringScheme {
+        &self.scheme
+    }
+
+    pub fn check(&self, line: &str) -> Status {
+        let mut nav = Navigator::with_brackets(self.brackets.clone());
+        for (offset, c) in line.char_indices() {
+            let expected = match nav.parse_char(c) {
+                Ok(()) => continue,
+                Err(ChunkError::UnexpectedInput(_)) if self.ignore_other => continue,
+                Err(ChunkError::UnexpectedInput(found)) => {
+                    return Status::Unexpected { offset, found }
+                }
+                Err(ChunkError::EmptyClose(_)) => None,
+                Err(ChunkError::IncorrectClose { expected, .. }) => self.brackets.closer(expected),
+            };
+            return Status::Corrupt {
+                offset,
+                found: c,
+                expected,
+                score: self.scheme.corruption(c).unwrap(),
+            };
+        }
+        let completion = nav.suffix();
+        if completion.is_empty() {
+            return Status::Valid;
+        }
+        let score = self.scheme.completion_score(&completion).unwrap();
+        Status::Incomplete { completion, score }
+    }
+
+    pub fn check_lines<S: AsRef<str>>(&self, lines: impl Iterator<Item = S>) -> Vec<Report> {
+        lines
+            .enumerate()
+            .map(|(num, line)| Report {
+                line: num + 1,
+                status: self.check(line.as_ref()),
+            })
+            .collect()
+    }
+}
+
+impl Default for Checker {
+    fn default() -> Self {
+        Checker::new(Brackets::default(), ScoringScheme::puzzle()).unwrap()
+    }
+}
+
+// The sum of the scores of the corrupt lines
+pub fn corruption_total(reports: &[Report]) -> usize {
+    reports
+        .iter()
+        .filter(|r| matches!(r.status, Status::Corrupt { .. }))
+        .fold(0, |total, r| total.saturating_add(r.status.score()))
+}
+
+// The median of the scores of the incomplete lines. With an even number, this is the lower of the
+// middle two, so it's always one of the scores. None if no line is incomplete.
+pub fn completion_median(reports: &[Report]) -> Option<usize> {
+    let mut scores: Vec<usize> = reports
+        .iter()
+        .filter(|r| matches!(r.status, Status::Incomplete { .. }))
+        .map(|r| r.status.score())
+        .collect();
+    if scores.is_empty() {
+        return None;
+    }
+    scores.sort_unstable();
+    Some(scores[(scores.len() - 1) / 2])
+}
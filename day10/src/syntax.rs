@@ -0,0 +1,325 @@
+use std::fmt;
+
+use crate::Brackets;
+
+/*
+Parses a whole line into a tree of chunks, rather than stopping at the first error. The line is
+repaired with the fewest insertions and deletions that balance it, and every edit made is reported
+as an error. Characters that aren't brackets are always deleted. A closing character that isn't
+kept is deleted, and an opening character that isn't matched gets its closing character inserted
+at the end of the chunk around it, or the end of the line. When a line can be repaired several
+ways with the same number of edits, openers are left unclosed rather than matched further along,
+which agrees with reading the line left to right.
+
+The fewest edits for every slice of the line are worked out from the shortest slices up, so a line
+of n characters takes O(n^3) time and O(n^2) space. Lines longer than MAX_REPAIR_LEN characters
+aren't parsed at all.
+*/
+
+// Repairing a line this long takes well under a second and a few megabytes
+pub const MAX_REPAIR_LEN: usize = 1000;
+
+// An opening character, where its closing character is, and the chunks inside it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    pub open: char,
+    // Byte offsets into the line
+    pub start: usize,
+    // None if the line never closes it
+    pub end: Option<usize>,
+    pub children: Vec<Chunk>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxError {
+    // Not one of the brackets
+    UnexpectedChar {
+        offset: usize,
+        found: char,
+    },
+    // A closing character with no opening character to match
+    UnmatchedClose {
+        offset: usize,
+        found: char,
+    },
+    // An opening character that's never closed, and where to insert its closing character
+    Unclosed {
+        offset: usize,
+        open: char,
+        close: char,
+        insert_at: usize,
+    },
+}
+
+impl SyntaxError {
+    // Where the error was found
+    pub fn offset(&self) -> usize {
+        match *self {
+            SyntaxError::UnexpectedChar { offset, .. } => offset,
+            SyntaxError::UnmatchedClose { offset, .. } => offset,
+            SyntaxError::Unclosed { offset, .. } => offset,
+        }
+    }
+
+    // The edit that fixes the error
+    pub fn edit(&self) -> Edit {
+        match *self {
+            SyntaxError::UnexpectedChar { offset, found } => Edit::Delete { offset, c: found },
+            SyntaxError::UnmatchedClose { offset, found } => Edit::Delete { offset, c: found },
+            SyntaxError::Unclosed {
+                close, insert_at, ..
+            } => Edit::Insert {
+                offset: insert_at,
+                c: close,
+            },
+        }
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyntaxError::UnexpectedChar { offset, found } => {
+                write!(f, "unexpected '{}' at byte {}", found, offset)
+            }
+            SyntaxError::UnmatchedClose { offset, found } => {
+                write!(f, "'{}' at byte {} closes nothing", found, offset)
+            }
+            SyntaxError::Unclosed {
+                offset,
+                open,
+                close,
+                insert_at,
+            } => write!(
+                f,
+                "'{}' at byte {} is never closed, expected '{}' at byte {}",
+                open, offset, close, insert_at
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    // Goes before the character at the offset, or at the end of the line
+    Insert { offset: usize, c: char },
+    Delete { offset: usize, c: char },
+}
+
+impl Edit {
+    pub fn offset(&self) -> usize {
+        match *self {
+            Edit::Insert { offset, .. } => offset,
+            Edit::Delete { offset, .. } => offset,
+        }
+    }
+}
+
+impl fmt::Display for Edit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Edit::Insert { offset, c } => write!(f, "insert '{}' at byte {}", c, offset),
+            Edit::Delete { offset, c } => write!(f, "delete '{}' at byte {}", c, offset),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parse {
+    // The chunks at the top level of the line
+    pub chunks: Vec<Chunk>,
+    // In the order of their offsets
+    pub errors: Vec<SyntaxError>,
+}
+
+impl Parse {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    // The edits that repair the line, in order. Insertions at the same offset are innermost first.
+    pub fn edits(&self) -> Vec<Edit> {
+        let mut edits = Vec::with_capacity(self.errors.len());
+        let mut inserts: Vec<(usize, usize, char)> = Vec::new();
+        for error in self.errors.iter() {
+            match error.edit() {
+                Edit::Insert { offset, c } => inserts.push((offset, error.offset(), c)),
+                delete => edits.push(delete),
+            }
+        }
+        // Of the openers closed at the same place, the later one is inside the earlier one
+        inserts.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+        edits.extend(
+            inserts
+                .into_iter()
+                .map(|(offset, _, c)| Edit::Insert { offset, c }),
+        );
+        // Stable, so insertions stay in order, and go before a deletion at the same offset
+        edits.sort_by_key(|e| (e.offset(), matches!(e, Edit::Delete { .. })));
+        edits
+    }
+
+    // The line with every edit applied
+    pub fn repaired(&self, line: &str) -> String {
+        let edits = self.edits();
+        let mut pending = edits.iter().peekable();
+        let mut repaired = String::with_capacity(line.len() + edits.len());
+        for (i, c) in line
+            .char_indices()
+            .chain(std::iter::once((line.len(), '\0')))
+        {
+            let mut keep = i < line.len();
+            while let Some(edit) = pending.next_if(|e| e.offset() == i) {
+                match *edit {
+                    Edit::Insert { c, .. } => repaired.push(c),
+                    Edit::Delete { .. } => keep = false,
+                }
+            }
+            if keep {
+                repaired.push(c);
+            }
+        }
+        repaired
+    }
+}
+
+struct Parser<'a> {
+    brackets: &'a Brackets,
+    // Each character with its byte offset
+    chars: Vec<(usize, char)>,
+    len: usize,
+    // The fewest edits to balance chars[i..j], at i * (n + 1) + j
+    cost: Vec<u32>,
+    errors: Vec<SyntaxError>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(brackets: &'a Brackets, line: &str) -> Self {
+        let chars: Vec<(usize, char)> = line.char_indices().collect();
+        let n = chars.len();
+        let mut parser = Parser {
+            brackets,
+            chars,
+            len: line.len(),
+            cost: vec![0; (n + 1) * (n + 1)],
+            errors: Vec::new(),
+        };
+        for i in (0..n).rev() {
+            let close = brackets.closer(parser.chars[i].1);
+            for j in i + 1..=n {
+                let mut best = 1 + parser.cost(i + 1, j);
+                if let Some(close) = close {
+                    for k in i + 1..j {
+                        if parser.chars[k].1 == close {
+                            best = best.min(parser.cost(i + 1, k) + parser.cost(k + 1, j));
+                        }
+                    }
+                }
+                parser.cost[i * (n + 1) + j] = best;
+            }
+        }
+        parser
+    }
+
+    fn cost(&self, i: usize, j: usize) -> u32 {
+        self.cost[i * (self.chars.len() + 1) + j]
+    }
+
+    fn offset(&self, i: usize) -> usize {
+        self.chars.get(i).map_or(self.len, |&(offset, _)| offset)
+    }
+
+    // Builds the chunks of the line along the cheapest repair, recording its edits. Nested chunks
+    // are kept on a stack rather than recursed into, however deep the line goes.
+    fn build(&mut self) -> Vec<Chunk> {
+        let mut stack = vec![Frame {
+            next: 0,
+            end: self.chars.len(),
+            chunks: Vec::new(),
+            inside: None,
+        }];
+        loop {
+            let frame = stack.last_mut().unwrap();
+            let (i, j) = (frame.next, frame.end);
+            if i == j {
+                let done = stack.pop().unwrap();
+                let Some((mut chunk, resume)) = done.inside else {
+                    return done.chunks;
+                };
+                chunk.children = done.chunks;
+                let parent = stack.last_mut().unwrap();
+                parent.chunks.push(chunk);
+                parent.next = resume;
+                continue;
+            }
+            let (offset, c) = self.chars[i];
+            let close = match self.brackets.closer(c) {
+                Some(close) => close,
+                None => {
+                    self.errors.push(match self.brackets.opener(c) {
+                        Some(_) => SyntaxError::UnmatchedClose { offset, found: c },
+                        None => SyntaxError::UnexpectedChar { offset, found: c },
+                    });
+                    frame.next += 1;
+                    continue;
+                }
+            };
+            let target = self.cost(i, j);
+            let (end, inner) = if target == 1 + self.cost(i + 1, j) {
+                // Left open, so everything after it up to j is inside it
+                self.errors.push(SyntaxError::Unclosed {
+                    offset,
+                    open: c,
+                    close,
+                    insert_at: self.offset(j),
+                });
+                (None, j)
+            } else {
+                let k = (i + 1..j)
+                    .find(|&k| {
+                        self.chars[k].1 == close
+                            && self.cost(i + 1, k) + self.cost(k + 1, j) == target
+                    })
+                    .unwrap();
+                (Some(self.chars[k].0), k)
+            };
+            let chunk = Chunk {
+                open: c,
+                start: offset,
+                end,
+                children: Vec::new(),
+            };
+            // Carries on after the closing character, or at j if there isn't one
+            let resume = if end.is_some() { inner + 1 } else { inner };
+            stack.push(Frame {
+                next: i + 1,
+                end: inner,
+                chunks: Vec::new(),
+                inside: Some((chunk, resume)),
+            });
+        }
+    }
+}
+
+// A slice of the line whose chunks are being built
+struct Frame {
+    // chars[next..end] is still to be built
+    next: usize,
+    end: usize,
+    chunks: Vec<Chunk>,
+    // The chunk the slice is inside, without its children yet, and where its slice carries on
+    inside: Option<(Chunk, usize)>,
+}
+
+// Parses a line, repairing it with as few edits as possible. None if the line is longer than
+// MAX_REPAIR_LEN characters.
+pub fn parse(brackets: &Brackets, line: &str) -> Option<Parse> {
+    if line.chars().count() > MAX_REPAIR_LEN {
+        return None;
+    }
+    let mut parser = Parser::new(brackets, line);
+    let chunks = parser.build();
+    let mut errors = parser.errors;
+    errors.sort_by_key(|e| e.offset());
+    Some(Parse { chunks, errors })
+}
@@ -0,0 +1,101 @@
+use app::grid::Grid;
+
+pub fn grid_incr(grid: &Grid<usize>) -> Grid<usize> {
+    grid.map(|_, &val| val + 1)
+}
+
+pub fn count_flashed(grid: &Grid<usize>) -> usize {
+    let mut count = 0;
+    grid.enumerate(|_, &val| {
+        if val == 0 {
+            count += 1;
+        }
+    });
+    count
+}
+
+fn flash_coord(grid: &mut Grid<usize>, row: usize, col: usize) {
+    let (rows, cols) = grid.size();
+    let miny = if row > 0 { row - 1 } else { row };
+    let maxy = if row + 1 < rows { row + 1 } else { row };
+    let minx = if col > 0 { col - 1 } else { col };
+    let maxx = if col + 1 < cols { col + 1 } else { col };
+    for y in miny..=maxy {
+        for x in minx..=maxx {
+            if (y != row || x != col) && grid[y][x] > 0 {
+                grid[y][x] += 1;
+            }
+        }
+    }
+}
+
+pub fn grid_flash(grid: &Grid<usize>) -> Grid<usize> {
+    let (rows, cols) = grid.size();
+    let mut g = grid.clone();
+    loop {
+        let mut stable = true;
+
+        for y in 0..rows {
+            for x in 0..cols {
+                if g[y][x] > 9 {
+                    g[y][x] = 0;
+                    flash_coord(&mut g, y, x);
+                    stable = false;
+                }
+            }
+        }
+        if stable {
+            break g;
+        }
+    }
+}
+
+pub fn parse_grid(lines: impl Iterator<Item = String>) -> Grid<usize> {
+    let mut lines = lines.map(|s| {
+        s.chars()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|c| c.to_digit(10).expect("Invalid digit") as usize)
+    });
+    let mut cells: Vec<usize> = lines.next().expect("No input").collect();
+    let cols = cells.len();
+    let mut rows = 1;
+    for line in lines {
+        let prev_size = cells.len();
+        cells.extend(line);
+        if cells.len() != prev_size + cols {
+            panic!("Inconsistent row width");
+        }
+        rows += 1;
+    }
+    Grid::new_with_cells(cells, rows, cols)
+}
+
+pub fn step(grid: &Grid<usize>) -> Grid<usize> {
+    grid_flash(&grid_incr(grid))
+}
+
+// Flashes over 100 steps
+pub fn part_a(path: &str) -> String {
+    let mut g = parse_grid(app::read_lines(path));
+    let mut flashes = 0;
+    for _ in 0..100 {
+        g = step(&g);
+        flashes += count_flashed(&g);
+    }
+    flashes.to_string()
+}
+
+// The first step on which every octopus flashes
+pub fn part_b(path: &str) -> String {
+    let mut g = parse_grid(app::read_lines(path));
+    let (rows, cols) = g.size();
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        g = step(&g);
+        if count_flashed(&g) == rows * cols {
+            break steps.to_string();
+        }
+    }
+}
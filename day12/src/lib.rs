@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+fn has_uppercase(s: &str) -> bool {
+    s.chars().any(char::is_uppercase)
+}
+
+pub struct Graph {
+    edges: HashMap<String, Vec<String>>,
+    empty: Vec<String>,
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Graph {
+            edges: HashMap::new(),
+            empty: Vec::new(),
+        }
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str) {
+        let entry = self.edges.entry(from.to_string());
+        entry.or_default().push(to.to_string());
+    }
+
+    pub fn out_nodes(&self, from: &str) -> impl Iterator<Item = &String> {
+        self.edges
+            .get(from)
+            .map_or_else(|| self.empty.iter(), |v| v.iter())
+    }
+
+    pub fn from_lines(lines: impl Iterator<Item = String>) -> Self {
+        let mut graph = Graph::new();
+        for line in lines {
+            let mut parts = line.split('-');
+            let start = parts.next().expect("Split should always produce something");
+            let end = parts.next().expect("Missing right side");
+            graph.add_edge(start, end);
+            graph.add_edge(end, start);
+        }
+        graph
+    }
+}
+
+#[derive(Clone)]
+pub struct Path {
+    nodes: Vec<String>,
+    has_dup: bool,
+}
+
+impl Path {
+    // A path that may not revisit any small cave starts out as if it had
+    // already used up its one duplicate visit.
+    pub fn new(s: &str, allow_dup: bool) -> Self {
+        Path {
+            nodes: vec![s.to_string()],
+            has_dup: !allow_dup,
+        }
+    }
+
+    pub fn nodes(&self) -> &[String] {
+        &self.nodes
+    }
+
+    pub fn head(&self) -> &str {
+        self.nodes.last().expect("Cannot have an empty path")
+    }
+
+    pub fn visit(&self, node: &str) -> Option<Self> {
+        if has_uppercase(node) || !self.nodes.iter().any(|n| n == node) {
+            let mut p = self.clone();
+            p.nodes.push(node.to_string());
+            Some(p)
+        } else if !self.has_dup && node != "start" {
+            let mut p = self.clone();
+            p.has_dup = true;
+            p.nodes.push(node.to_string());
+            Some(p)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn all_paths(g: &Graph, allow_dup: bool) -> Vec<Path> {
+    let mut paths = Vec::new();
+    let mut queue = vec![Path::new("start", allow_dup)];
+    while let Some(path) = queue.pop() {
+        let head = path.head();
+        if head == "end" {
+            paths.push(path);
+            continue;
+        }
+        for tail in g.out_nodes(head) {
+            if let Some(new_path) = path.visit(tail) {
+                queue.push(new_path);
+            }
+        }
+    }
+    paths
+}
+
+// Paths that visit small caves at most once
+pub fn part_a(path: &str) -> String {
+    let graph = Graph::from_lines(app::read_lines(path));
+    all_paths(&graph, false).len().to_string()
+}
+
+// Paths that may visit one small cave twice
+pub fn part_b(path: &str) -> String {
+    let graph = Graph::from_lines(app::read_lines(path));
+    all_paths(&graph, true).len().to_string()
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use app::point::Point2D;
+
+pub type Point = Point2D<usize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fold<T> {
+    X(T),
+    Y(T),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseFoldError {
+    MatchError,
+    ParseError(ParseIntError),
+}
+
+impl<T> FromStr for Fold<T>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    type Err = ParseFoldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^fold along (x|y)=(\d+)$").expect("Failed to compile regex");
+        let caps = re.captures(s).ok_or(Self::Err::MatchError)?;
+        let val = caps[2].parse::<T>().map_err(Self::Err::ParseError)?;
+        Ok(if &caps[1] == "x" {
+            Fold::X(val)
+        } else {
+            Fold::Y(val)
+        })
+    }
+}
+
+pub fn fold_point(fold: &Fold<usize>, point: &Point) -> Point {
+    let p = *point;
+    match fold {
+        Fold::X(axis) => {
+            if p.x > *axis {
+                Point::new(2 * (*axis) - p.x, p.y)
+            } else {
+                p
+            }
+        }
+        Fold::Y(axis) => {
+            if p.y > *axis {
+                Point::new(p.x, 2 * (*axis) - p.y)
+            } else {
+                p
+            }
+        }
+    }
+}
+
+pub fn parse_points(lines: &mut impl Iterator<Item = String>) -> HashSet<Point> {
+    let mut points: HashSet<Point> = HashSet::new();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        points.insert(line.parse::<Point>().unwrap());
+    }
+    points
+}
+
+pub fn parse_folds(lines: impl Iterator<Item = String>) -> Vec<Fold<usize>> {
+    lines
+        .map(|line| line.parse::<Fold<usize>>().unwrap())
+        .collect()
+}
+
+pub fn apply_fold(fold: &Fold<usize>, points: HashSet<Point>) -> HashSet<Point> {
+    HashSet::from_iter(points.into_iter().map(|p| fold_point(fold, &p)))
+}
+
+pub fn render(points: &HashSet<Point>) -> String {
+    let (maxx, maxy) = points
+        .iter()
+        .fold((0, 0), |m, p| (p.x.max(m.0), p.y.max(m.1)));
+    let mut result = String::new();
+    for y in 0..=maxy {
+        for x in 0..=maxx {
+            let p = Point::new(x, y);
+            result.push(if points.contains(&p) { '#' } else { ' ' })
+        }
+        result.push('\n');
+    }
+    result
+}
+
+// Points left after the first fold
+pub fn part_a(path: &str) -> String {
+    let mut lines = app::read_lines(path);
+    let points = parse_points(&mut lines);
+    let folds = parse_folds(lines);
+    let fold = folds.first().expect("No folds");
+    apply_fold(fold, points).len().to_string()
+}
+
+// The code drawn by the points after every fold
+pub fn part_b(path: &str) -> String {
+    let mut lines = app::read_lines(path);
+    let mut points = parse_points(&mut lines);
+    for fold in parse_folds(lines).iter() {
+        points = apply_fold(fold, points);
+    }
+    render(&points)
+}
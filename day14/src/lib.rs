@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub enum ParseRuleError {
+    InvalidSyntax,
+    WrongSizeKey,
+    WrongSizeValue,
+}
+
+pub type CharCount = HashMap<char, usize>;
+
+pub trait Mergeable {
+    fn merge(&mut self, rhs: &Self);
+}
+
+impl Mergeable for CharCount {
+    fn merge(&mut self, rhs: &Self) {
+        for (k, v) in rhs.iter() {
+            let entry = self.entry(*k).or_default();
+            *entry += v;
+        }
+    }
+}
+
+pub struct Expansion {
+    memory: HashMap<(char, char, usize), CharCount>,
+    rules: HashMap<(char, char), char>,
+}
+
+impl Default for Expansion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Expansion {
+    pub fn new() -> Self {
+        Expansion {
+            memory: HashMap::new(),
+            rules: HashMap::new(),
+        }
+    }
+
+    pub fn add_rule(&mut self, k1: char, k2: char, v: char) {
+        self.rules.insert((k1, k2), v);
+    }
+
+    pub fn expand_str(&mut self, s: &str, depth: usize) -> CharCount {
+        let mut counts = CharCount::new();
+        for c in s.chars() {
+            let entry = counts.entry(c).or_default();
+            *entry += 1;
+        }
+        let pairs = s.chars().zip(s.chars().skip(1));
+        for (a, b) in pairs {
+            counts.merge(&self.expand(a, b, depth));
+        }
+        counts
+    }
+
+    pub fn expand(&mut self, k1: char, k2: char, depth: usize) -> CharCount {
+        if depth == 0 {
+            return CharCount::new();
+        }
+        if let Some(count) = self.memory.get(&(k1, k2, depth)) {
+            return count.clone();
+        }
+        let mut counts = CharCount::new();
+        let c = *self
+            .rules
+            .get(&(k1, k2))
+            .expect("Missing rule for char pair");
+        counts.merge(&self.expand(k1, c, depth - 1));
+        counts.merge(&self.expand(c, k2, depth - 1));
+        let entry = counts.entry(c).or_default();
+        *entry += 1;
+        self.memory.insert((k1, k2, depth), counts.clone());
+        counts
+    }
+}
+
+pub fn parse_rule(s: &str) -> Result<(char, char, char), ParseRuleError> {
+    let mut parts = s.split(" -> ");
+    let k: Vec<char> = parts
+        .next()
+        .map(|s| s.chars().collect())
+        .ok_or(ParseRuleError::InvalidSyntax)?;
+    let v: Vec<char> = parts
+        .next()
+        .map(|s| s.chars().collect())
+        .ok_or(ParseRuleError::InvalidSyntax)?;
+    match (k.len(), v.len()) {
+        (2, 1) => Ok((k[0], k[1], v[0])),
+        (2, _) => Err(ParseRuleError::WrongSizeValue),
+        _ => Err(ParseRuleError::WrongSizeKey),
+    }
+}
+
+// Returns the least and most frequent characters with their counts
+pub fn min_max(counts: &CharCount) -> ((char, usize), (char, usize)) {
+    let minmax = counts
+        .iter()
+        .fold((' ', usize::MAX, ' ', usize::MIN), |acc, (k, v)| {
+            let min = if *v < acc.1 { (*k, *v) } else { (acc.0, acc.1) };
+            let max = if *v > acc.3 { (*k, *v) } else { (acc.2, acc.3) };
+            (min.0, min.1, max.0, max.1)
+        });
+    ((minmax.0, minmax.1), (minmax.2, minmax.3))
+}
+
+// The most common element's count less the least common's after some steps
+fn spread(path: &str, steps: usize) -> String {
+    let mut lines = app::read_lines(path);
+    let input = lines.next().expect("No input");
+    lines.next(); // Consume blank separator
+
+    let mut expansion = Expansion::new();
+    lines
+        .map(|l| parse_rule(&l).unwrap())
+        .for_each(|(k1, k2, v)| expansion.add_rule(k1, k2, v));
+    let ((_, min), (_, max)) = min_max(&expansion.expand_str(&input, steps));
+    (max - min).to_string()
+}
+
+pub fn part_a(path: &str) -> String {
+    spread(path, 10)
+}
+
+pub fn part_b(path: &str) -> String {
+    spread(path, 40)
+}
@@ -0,0 +1,165 @@
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct Rect {
+    xmin: isize,
+    xmax: isize,
+    ymin: isize,
+    ymax: isize,
+}
+
+impl Rect {
+    pub fn new(x1: isize, x2: isize, y1: isize, y2: isize) -> Self {
+        let (xmin, xmax) = if x1 <= x2 { (x1, x2) } else { (x2, x1) };
+        let (ymin, ymax) = if y1 <= y2 { (y1, y2) } else { (y2, y1) };
+        Rect {
+            xmin,
+            ymin,
+            xmax,
+            ymax,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Solution {
+    pub xvel: isize,
+    pub yvel: isize,
+    pub steps: isize,
+}
+
+impl Solution {
+    pub fn pos_x(&self) -> isize {
+        let (xvel, steps) = (self.xvel, self.steps);
+        if xvel < steps {
+            xvel * (xvel + 1) / 2
+        } else {
+            xvel * steps - (steps - 1) * steps / 2
+        }
+    }
+
+    pub fn pos_y(&self) -> isize {
+        self.yvel * self.steps - self.steps * (self.steps - 1) / 2
+    }
+
+    pub fn max_height(&self) -> isize {
+        if self.yvel <= 0 {
+            return 0;
+        }
+        self.yvel * (self.yvel + 1) / 2
+    }
+}
+
+pub struct YIter {
+    rect: Rect,
+    current: Solution,
+}
+
+impl YIter {
+    pub fn new(r: &Rect) -> Self {
+        YIter {
+            rect: r.clone(),
+            current: Solution {
+                xvel: 0,
+                yvel: -r.ymin,
+                steps: 0,
+            },
+        }
+    }
+}
+
+impl Iterator for YIter {
+    type Item = Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (top, bottom) = (self.rect.ymax, self.rect.ymin);
+        while self.current.yvel >= bottom {
+            self.current.steps += 1;
+            let y = self.current.pos_y();
+            if y < bottom {
+                self.current.yvel -= 1;
+                self.current.steps = 0;
+            } else if y <= top {
+                return Some(self.current.clone());
+            }
+        }
+        None
+    }
+}
+
+pub struct XIter {
+    rect: Rect,
+    current: Solution,
+    xmax: isize,
+}
+
+impl XIter {
+    pub fn new(r: &Rect, base: &Solution) -> Self {
+        let xmin = if r.xmin < 0 { r.xmin } else { 0 };
+        let xmax = if r.xmax > 0 { r.xmax } else { 0 };
+        let mut base = base.clone();
+        base.xvel = xmin - 1;
+        XIter {
+            rect: r.clone(),
+            current: base,
+            xmax,
+        }
+    }
+}
+
+impl Iterator for XIter {
+    type Item = Solution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (left, right) = (self.rect.xmin, self.rect.xmax);
+        while self.current.xvel <= self.xmax {
+            self.current.xvel += 1;
+            let x = self.current.pos_x();
+            if x >= left && x <= right {
+                return Some(self.current.clone());
+            }
+        }
+        None
+    }
+}
+
+// Every (velocity, steps) combination that lands in the target. The x velocities for each candidate
+// y velocity are searched in parallel, and results keep the order of a sequential search.
+pub fn all_solutions(rect: &Rect) -> Vec<Solution> {
+    let bases: Vec<Solution> = YIter::new(rect).collect();
+    app::parallel::map(&bases, |base| XIter::new(rect, base).collect::<Vec<_>>())
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+pub fn parse_target(input: &str) -> Rect {
+    let re = Regex::new(r"^target area: x=(-?\d+)..(-?\d+), y=(-?\d+)..(-?\d+)$").unwrap();
+    let caps = re
+        .captures(input)
+        .expect("Input does not match expectation");
+    Rect::new(
+        caps[1].parse().unwrap(),
+        caps[2].parse().unwrap(),
+        caps[3].parse().unwrap(),
+        caps[4].parse().unwrap(),
+    )
+}
+
+// The greatest height reached by a shot that hits the target
+pub fn part_a(path: &str) -> String {
+    let rect = parse_target(&app::read_line(path));
+    let solution = YIter::new(&rect).next().expect("No solution found");
+    solution.max_height().to_string()
+}
+
+// Initial velocities that hit the target
+pub fn part_b(path: &str) -> String {
+    let rect = parse_target(&app::read_line(path));
+    // Some shots hit the target area more than once, so only count distinct velocities
+    let uniques: std::collections::HashSet<(isize, isize)> = all_solutions(&rect)
+        .into_iter()
+        .map(|solution| (solution.xvel, solution.yvel))
+        .collect();
+    uniques.len().to_string()
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Player {
+    pub score: usize,
+    pub position: usize,
+}
+
+impl Player {
+    pub fn new(position: usize) -> Self {
+        Player { score: 0, position }
+    }
+
+    pub fn advance(&mut self, n: usize) {
+        self.position = ((self.position + n - 1) % 10) + 1;
+        self.score += self.position;
+    }
+
+    pub fn split(&self) -> [Self; 27] {
+        let mut states = [*self; 27];
+        let mut i = 0;
+        for d1 in 1..=3 {
+            for d2 in 1..=3 {
+                for d3 in 1..=3 {
+                    states[i].advance(d1 + d2 + d3);
+                    i += 1;
+                }
+            }
+        }
+        states
+    }
+
+    // Only used by the Dirac dice game, which plays to 21
+    pub fn won(&self) -> bool {
+        self.score >= 21
+    }
+}
+
+#[derive(Default)]
+pub struct Die {
+    rolls: usize,
+}
+
+impl Die {
+    pub fn new() -> Self {
+        Die { rolls: 0 }
+    }
+
+    pub fn roll(&mut self) -> usize {
+        self.rolls += 1;
+        ((self.rolls - 1) % 100) + 1
+    }
+
+    pub fn num_rolls(&self) -> usize {
+        self.rolls
+    }
+}
+
+pub struct DeterministicGame {
+    die: Die,
+    p1_turn: bool,
+    player1: Player,
+    player2: Player,
+}
+
+impl DeterministicGame {
+    pub fn new(p1: usize, p2: usize) -> Self {
+        DeterministicGame {
+            die: Die::new(),
+            p1_turn: true,
+            player1: Player::new(p1),
+            player2: Player::new(p2),
+        }
+    }
+
+    pub fn take_turn(&mut self) {
+        let player = if self.p1_turn {
+            &mut self.player1
+        } else {
+            &mut self.player2
+        };
+        let delta = self.die.roll() + self.die.roll() + self.die.roll();
+        player.advance(delta);
+        self.p1_turn = !self.p1_turn;
+    }
+
+    pub fn finished(&self) -> bool {
+        self.player1.score >= 1000 || self.player2.score >= 1000
+    }
+
+    pub fn scores(&self) -> (usize, usize) {
+        (self.player1.score, self.player2.score)
+    }
+
+    pub fn num_rolls(&self) -> usize {
+        self.die.num_rolls()
+    }
+}
+
+pub struct DiracGame {
+    p1_turn: bool,
+    states: HashMap<(Player, Player), usize>,
+}
+
+impl DiracGame {
+    pub fn new(p1: usize, p2: usize) -> Self {
+        let p1 = Player::new(p1);
+        let p2 = Player::new(p2);
+        DiracGame {
+            p1_turn: true,
+            states: HashMap::from([((p1, p2), 1)]),
+        }
+    }
+
+    pub fn take_turn(&mut self) {
+        let mut state_new = HashMap::new();
+        self.states.iter().for_each(|(&(p1, p2), &count)| {
+            if p1.won() || p2.won() {
+                *state_new.entry((p1, p2)).or_default() += count;
+            } else if self.p1_turn {
+                for state in p1.split() {
+                    *state_new.entry((state, p2)).or_default() += count;
+                }
+            } else {
+                for state in p2.split() {
+                    *state_new.entry((p1, state)).or_default() += count;
+                }
+            }
+        });
+        self.states = state_new;
+        self.p1_turn = !self.p1_turn;
+    }
+
+    pub fn finished(&self) -> bool {
+        self.states.keys().all(|(p1, p2)| p1.won() || p2.won())
+    }
+
+    pub fn scores(&self) -> (usize, usize) {
+        self.states.iter().fold((0, 0), |acc, ((p1, p2), &count)| {
+            if p1.won() {
+                (acc.0 + count, acc.1)
+            } else if p2.won() {
+                (acc.0, acc.1 + count)
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+pub fn read_position(s: &str) -> usize {
+    let re = Regex::new(r"^Player \d starting position: (\d+)").expect("Failed to compile regex");
+    if let Some(caps) = re.captures(s) {
+        caps[1].parse().expect("Non-numeric position")
+    } else {
+        panic!("Invalid input")
+    }
+}
+
+fn read_positions(path: &str) -> (usize, usize) {
+    let mut lines = app::read_lines(path);
+    let p1 = read_position(&lines.next().expect("Missing player 1"));
+    let p2 = read_position(&lines.next().expect("Missing player 2"));
+    (p1, p2)
+}
+
+// The losing score times the number of rolls with the deterministic die
+pub fn part_a(path: &str) -> String {
+    let (p1, p2) = read_positions(path);
+    let mut game = DeterministicGame::new(p1, p2);
+    while !game.finished() {
+        game.take_turn();
+    }
+    let scores = game.scores();
+    (scores.0.min(scores.1) * game.num_rolls()).to_string()
+}
+
+// The number of universes the more successful player wins in
+pub fn part_b(path: &str) -> String {
+    let (p1, p2) = read_positions(path);
+    let mut game = DiracGame::new(p1, p2);
+    while !game.finished() {
+        game.take_turn();
+    }
+    let scores = game.scores();
+    scores.0.max(scores.1).to_string()
+}
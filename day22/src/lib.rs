@@ -0,0 +1,172 @@
+use regex::Regex;
+
+use app::point::Point3D;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cuboid {
+    pub min: Point3D<isize>,
+    pub max: Point3D<isize>,
+}
+
+impl Cuboid {
+    pub fn new(min: Point3D<isize>, max: Point3D<isize>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn volume(&self) -> isize {
+        let dx = self.max.x - self.min.x;
+        let dy = self.max.y - self.min.y;
+        let dz = self.max.z - self.min.z;
+        dx * dy * dz
+    }
+
+    pub fn contains(&self, other: &Cuboid) -> bool {
+        self.intersection(other) == Some(*other)
+    }
+
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = Point3D::new(
+            self.min.x.max(other.min.x),
+            self.min.y.max(other.min.y),
+            self.min.z.max(other.min.z),
+        );
+        let max = Point3D::new(
+            self.max.x.min(other.max.x),
+            self.max.y.min(other.max.y),
+            self.max.z.min(other.max.z),
+        );
+        if min.x >= max.x || min.y >= max.y || min.z >= max.z {
+            None
+        } else {
+            Some(Cuboid::new(min, max))
+        }
+    }
+}
+
+pub struct NegationTree {
+    region: Cuboid,
+    negations: Vec<NegationTree>,
+}
+
+impl NegationTree {
+    pub fn new(region: Cuboid) -> Self {
+        Self {
+            region,
+            negations: Vec::new(),
+        }
+    }
+
+    pub fn negate(&mut self, region: &Cuboid) {
+        let intersection = match self.region.intersection(region) {
+            Some(cuboid) => cuboid,
+            None => return,
+        };
+        for negation in self.negations.iter_mut() {
+            negation.negate(&intersection);
+        }
+        self.negations.push(Self::new(intersection));
+    }
+
+    pub fn volume(&self) -> isize {
+        let neg_volume: isize = self
+            .negations
+            .iter()
+            .map(|negation| negation.volume())
+            .sum();
+        if neg_volume > self.region.volume() {
+            panic!("Encountered negative volume space");
+        }
+        self.region.volume() - neg_volume
+    }
+}
+
+pub struct NegationSpace {
+    trees: Vec<NegationTree>,
+}
+
+impl Default for NegationSpace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NegationSpace {
+    pub fn new() -> Self {
+        Self { trees: Vec::new() }
+    }
+
+    pub fn add(&mut self, region: &Cuboid) {
+        self.subtract(region);
+        self.trees.push(NegationTree::new(*region));
+    }
+
+    pub fn subtract(&mut self, region: &Cuboid) {
+        for tree in self.trees.iter_mut() {
+            tree.negate(region);
+        }
+    }
+
+    pub fn volume(&self) -> isize {
+        self.trees.iter().map(|tree| tree.volume()).sum()
+    }
+}
+
+pub struct Command {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+pub fn parse_command(s: &str) -> Command {
+    let num_range = r"(-?\d+)..(-?\d+)";
+    let mut pattern = String::from("(on|off) x=");
+    pattern += num_range;
+    pattern += ",y=";
+    pattern += num_range;
+    pattern += ",z=";
+    pattern += num_range;
+    let re = Regex::new(&pattern).expect("Failed to compile regex");
+    let caps = re.captures(s).expect("Failed to match input");
+    let on = &caps[1] == "on";
+    let (x1, x2, y1, y2, z1, z2) = (
+        caps[2].parse::<isize>().expect("Invalid number"),
+        caps[3].parse::<isize>().expect("Invalid number"),
+        caps[4].parse::<isize>().expect("Invalid number"),
+        caps[5].parse::<isize>().expect("Invalid number"),
+        caps[6].parse::<isize>().expect("Invalid number"),
+        caps[7].parse::<isize>().expect("Invalid number"),
+    );
+    let min = Point3D::new(x1, y1, z1);
+    let max = Point3D::new(x2 + 1, y2 + 1, z2 + 1);
+    Command {
+        on,
+        cuboid: Cuboid::new(min, max),
+    }
+}
+
+// Cubes left on, counting only commands inside the region if there is one
+fn cubes_on(path: &str, region: Option<Cuboid>) -> String {
+    let mut space = NegationSpace::new();
+    for command in app::read_lines(path).map(|s| parse_command(&s)) {
+        if region.is_some_and(|r| !r.contains(&command.cuboid)) {
+            continue;
+        }
+        if command.on {
+            space.add(&command.cuboid);
+        } else {
+            space.subtract(&command.cuboid);
+        }
+    }
+    space.volume().to_string()
+}
+
+// Cubes on within -50..=50 on each axis
+pub fn part_a(path: &str) -> String {
+    // Cuboids exclude their max corner, so the region extends one past 50
+    let region = Cuboid::new(Point3D::new(-50, -50, -50), Point3D::new(51, 51, 51));
+    cubes_on(path, Some(region))
+}
+
+// Cubes on anywhere
+pub fn part_b(path: &str) -> String {
+    cubes_on(path, None)
+}
@@ -0,0 +1,212 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::panic;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+use app::answers::{AnswerKey, AnswerStore, Check};
+
+mod solutions;
+
+use solutions::{Solution, SOLUTIONS};
+
+const USAGE: &str = "Usage: runner [--input FILE] [--answers FILE] [--accept] [--jobs N] [DAY...]";
+
+struct Options {
+    input: String,
+    answers: String,
+    accept: bool,
+    jobs: usize,
+    days: Vec<u8>,
+}
+
+// The answer and its store key, or why the solution was skipped
+type Outcome = Result<(AnswerKey, String), String>;
+
+thread_local! {
+    // The message and location of the last panic on this thread, kept by the hook main installs
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        input: "input.txt".to_string(),
+        answers: "answers.txt".to_string(),
+        accept: false,
+        jobs: app::parallel::num_threads(),
+        days: Vec::new(),
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => options.input = args.next().ok_or("Missing value for --input")?,
+            "--answers" => options.answers = args.next().ok_or("Missing value for --answers")?,
+            "--accept" => options.accept = true,
+            "--jobs" => {
+                let jobs = args.next().ok_or("Missing value for --jobs")?;
+                options.jobs = match jobs.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid number of jobs {}", jobs)),
+                };
+            }
+            day => {
+                let day = day
+                    .trim_start_matches("day")
+                    .parse::<u8>()
+                    .map_err(|_| format!("Invalid day {}", day))?;
+                options.days.push(day);
+            }
+        }
+    }
+    Ok(options)
+}
+
+// Multi-line answers (e.g. rendered letters) are printed below the result line
+fn print_result(label: &str, answer: &str, status: &str, previous: Option<&str>) {
+    if answer.contains('\n') || previous.is_some_and(|p| p.contains('\n')) {
+        println!("{}: [{}]\n{}", label, status, answer.trim_end());
+        if let Some(previous) = previous {
+            println!("Previously accepted:\n{}", previous.trim_end());
+        }
+    } else if let Some(previous) = previous {
+        println!("{}: {} [{}, was {}]", label, answer, status, previous);
+    } else {
+        println!("{}: {} [{}]", label, answer, status);
+    }
+}
+
+fn run(solution: &Solution, input: &str) -> Outcome {
+    // Inputs live alongside each day's crate, relative to the workspace root
+    let path = format!("day{:02}/{}", solution.day, input);
+    let contents = fs::read(&path).map_err(|err| format!("cannot read {}: {}", path, err))?;
+    let key = AnswerKey::new(solution.day, solution.part, &contents);
+    // Solutions panic on bad input, which shouldn't stop the other days or lose accepted answers
+    LAST_PANIC.take();
+    let answer = panic::catch_unwind(|| (solution.solve)(&path)).map_err(|payload| {
+        // A panic passed on from another thread, e.g. an app::parallel worker, only has its payload
+        let reason = LAST_PANIC
+            .take()
+            .unwrap_or_else(|| match payload.downcast_ref::<&str>() {
+                Some(s) => s.to_string(),
+                None => payload
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .unwrap_or_else(|| "unknown cause".to_string()),
+            });
+        format!("panicked, {}", reason)
+    })?;
+    Ok((key, answer))
+}
+
+// Runs the solutions on a pool of worker threads, passing each outcome to report in the same order
+// as solutions regardless of which finishes first.
+fn run_all<F>(solutions: &[&Solution], input: &str, jobs: usize, mut report: F)
+where
+    F: FnMut(&Solution, Outcome),
+{
+    let jobs = jobs.min(solutions.len());
+    // Solutions that run in parallel themselves share out the threads between the jobs
+    let threads = app::parallel::num_threads() / jobs.max(1);
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|s| {
+        for _ in 0..jobs {
+            let tx = tx.clone();
+            let next = &next;
+            s.spawn(move || {
+                app::parallel::with_threads(threads, || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    match solutions.get(index) {
+                        Some(solution) => tx
+                            .send((index, run(solution, input)))
+                            .expect("Result receiver went away"),
+                        None => break,
+                    }
+                })
+            });
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+        for (index, outcome) in rx {
+            pending.insert(index, outcome);
+            while let Some(outcome) = pending.remove(&reported) {
+                report(solutions[reported], outcome);
+                reported += 1;
+            }
+        }
+    });
+}
+
+fn main() {
+    let options = parse_args().unwrap_or_else(|err| {
+        eprintln!("{}\n{}", err, USAGE);
+        process::exit(2);
+    });
+    let mut store = AnswerStore::load(&options.answers).expect("Unable to read answer store");
+
+    let selected: Vec<&Solution> = SOLUTIONS
+        .iter()
+        .filter(|s| options.days.is_empty() || options.days.contains(&s.day))
+        .collect();
+    let mut changed = 0;
+    let mut accepted = 0;
+    // A panicking solution is reported as skipped along with its panic, rather than printing the
+    // default message as well
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let message = info.payload_as_str().unwrap_or("unknown cause");
+        let panic = match info.location() {
+            Some(location) => format!("{} at {}", message, location),
+            None => message.to_string(),
+        };
+        LAST_PANIC.set(Some(panic));
+    }));
+    run_all(
+        &selected,
+        &options.input,
+        options.jobs,
+        |solution, outcome| {
+            let label = format!("day{:02} {}", solution.day, solution.part);
+            let (key, answer) = match outcome {
+                Ok(result) => result,
+                Err(reason) => {
+                    println!("{}: skipped, {}", label, reason);
+                    return;
+                }
+            };
+            match store.check(&key, &answer) {
+                Check::Matches => print_result(&label, &answer, "ok", None),
+                Check::New if options.accept => {
+                    print_result(&label, &answer, "new, accepted", None)
+                }
+                Check::New => print_result(&label, &answer, "new", None),
+                Check::Changed { accepted } if options.accept => {
+                    print_result(&label, &answer, "CHANGED, accepted", Some(&accepted))
+                }
+                Check::Changed { accepted } => {
+                    changed += 1;
+                    print_result(&label, &answer, "CHANGED", Some(&accepted))
+                }
+            }
+            if options.accept && store.accept(key, &answer).as_deref() != Some(answer.as_str()) {
+                accepted += 1;
+            }
+        },
+    );
+    panic::set_hook(default_hook);
+
+    if accepted > 0 {
+        store.save().expect("Unable to write answer store");
+        println!("Recorded {} answers in {}", accepted, options.answers);
+    }
+    if changed > 0 {
+        println!("{} answers differ from accepted answers", changed);
+        process::exit(1);
+    }
+}
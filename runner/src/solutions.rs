@@ -0,0 +1,57 @@
+// Each solution takes the path of its input file and returns the puzzle answer.
+pub struct Solution {
+    pub day: u8,
+    pub part: char,
+    pub solve: fn(&str) -> String,
+}
+
+const fn solution(day: u8, part: char, solve: fn(&str) -> String) -> Solution {
+    Solution { day, part, solve }
+}
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution(1, 'a', day01::part_a),
+    solution(1, 'b', day01::part_b),
+    solution(2, 'a', day02::part_a),
+    solution(2, 'b', day02::part_b),
+    solution(3, 'a', day03::part_a),
+    solution(3, 'b', day03::part_b),
+    solution(4, 'a', day04::part_a),
+    solution(4, 'b', day04::part_b),
+    solution(5, 'a', day05::part_a),
+    solution(5, 'b', day05::part_b),
+    solution(6, 'a', day06::part_a),
+    solution(6, 'b', day06::part_b),
+    solution(7, 'a', day07::part_a),
+    solution(7, 'b', day07::part_b),
+    solution(8, 'a', day08::part_a),
+    solution(8, 'b', day08::part_b),
+    solution(9, 'a', day09::part_a),
+    solution(9, 'b', day09::part_b),
+    solution(10, 'a', day10::part_a),
+    solution(10, 'b', day10::part_b),
+    solution(11, 'a', day11::part_a),
+    solution(11, 'b', day11::part_b),
+    solution(12, 'a', day12::part_a),
+    solution(12, 'b', day12::part_b),
+    solution(13, 'a', day13::part_a),
+    solution(13, 'b', day13::part_b),
+    solution(14, 'a', day14::part_a),
+    solution(14, 'b', day14::part_b),
+    solution(15, 'a', day15::part_a),
+    solution(15, 'b', day15::part_b),
+    solution(16, 'a', day16::part_a),
+    solution(16, 'b', day16::part_b),
+    solution(17, 'a', day17::part_a),
+    solution(17, 'b', day17::part_b),
+    solution(18, 'a', day18::part_a),
+    solution(18, 'b', day18::part_b),
+    solution(19, 'a', day19::part_a),
+    solution(19, 'b', day19::part_b),
+    solution(20, 'a', day20::part_a),
+    solution(20, 'b', day20::part_b),
+    solution(21, 'a', day21::part_a),
+    solution(21, 'b', day21::part_b),
+    solution(22, 'a', day22::part_a),
+    solution(22, 'b', day22::part_b),
+];